@@ -0,0 +1,30 @@
+extern crate your;
+
+use std::time::Instant;
+
+use your::Matrix;
+
+const SIZE: usize = 512;
+const ROUNDS: u32 = 100;
+
+fn main() {
+    let values: Vec<f64> = (0..SIZE * SIZE).map(|i| i as f64).collect();
+    let step = Matrix::new(SIZE, SIZE, &values);
+
+    let mut sum = Matrix::zeros(SIZE, SIZE);
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        sum = &sum + &step;
+    }
+    println!("sum = &sum + &step: {:?} per call", start.elapsed() / ROUNDS);
+
+    // `+=` writes into the existing buffer, so the loop never allocates.
+    let mut accumulated = Matrix::zeros(SIZE, SIZE);
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        accumulated += &step;
+    }
+    println!("accumulated += &step: {:?} per call", start.elapsed() / ROUNDS);
+
+    assert_eq!(sum, accumulated);
+}
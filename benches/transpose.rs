@@ -0,0 +1,41 @@
+extern crate your;
+
+use std::time::Instant;
+
+use your::Matrix;
+
+const SIZE: usize = 1024;
+const ROUNDS: u32 = 10;
+
+fn main() {
+    let values: Vec<u64> = (0..(SIZE * SIZE) as u64).collect();
+    let mut m = Matrix::new(SIZE, SIZE, &values);
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        m = m.transpose();
+    }
+    println!("transpose:          {:?} per call", start.elapsed() / ROUNDS);
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        m.transpose_in_place();
+    }
+    println!("transpose_in_place: {:?} per call", start.elapsed() / ROUNDS);
+
+    assert_eq!(m.data(), &values);
+
+    let mut r = Matrix::new(SIZE / 2, SIZE * 2, &values);
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        r = r.transpose();
+    }
+    println!("transpose (rectangular):          {:?} per call", start.elapsed() / ROUNDS);
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        r.transpose_in_place();
+    }
+    println!("transpose_in_place (rectangular): {:?} per call", start.elapsed() / ROUNDS);
+}
@@ -0,0 +1,90 @@
+use std::{error, fmt};
+
+/// Errors reported by the fallible `Matrix` operations.
+#[derive(PartialEq, Debug, Clone)]
+pub enum MatrixError {
+    /// A slice of values did not have the number of elements the operation needs.
+    LengthMismatch { expected: usize, actual: usize },
+    /// Row `row` of the input had `actual` elements while earlier rows had `expected`.
+    RaggedRows { row: usize, expected: usize, actual: usize },
+    /// The shapes of the operands of `op` are incompatible. `op` is the verb
+    /// naming the operation, such as `"add"` or `"multiply"`.
+    DimensionMismatch { left: (usize, usize), right: (usize, usize), op: &'static str },
+    /// `op` needs a square matrix but was given one of shape `shape`.
+    NotSquare { shape: (usize, usize), op: &'static str },
+    /// The matrix is singular (or numerically indistinguishable from singular).
+    Singular,
+    /// The matrix is not positive definite: a nonpositive pivot was encountered.
+    NotPositiveDefinite,
+    /// `op` needs a symmetric matrix but was given one with `a[i][j] != a[j][i]`
+    /// beyond the tolerance at position `at`.
+    NotSymmetric { at: (usize, usize), op: &'static str },
+    /// The columns of the matrix are (numerically) linearly dependent.
+    RankDeficient,
+    /// An iterative method did not reach its tolerance within `iterations`
+    /// iterations. `residual` is the final residual norm and `last` the last
+    /// iterate, which may still be a useful approximation.
+    NoConvergence { iterations: usize, residual: f64, last: Vec<f64> },
+    /// The element at `at`, whose `Debug` representation is `value`, could
+    /// not be converted to the target element type.
+    ConversionFailed { at: (usize, usize), value: String },
+    /// `op` was given the row or column position `index`, which is out of
+    /// bounds for a matrix of shape `shape`.
+    IndexOutOfBounds { index: usize, shape: (usize, usize), op: &'static str },
+    /// The block at position `block` of a block grid has shape `shape`, but
+    /// the other blocks in its block row and block column require `expected`.
+    BlockShape { block: (usize, usize), shape: (usize, usize), expected: (usize, usize) },
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MatrixError::LengthMismatch { expected, actual } => {
+                write!(f, "expected {} values, got {}", expected, actual)
+            }
+            MatrixError::RaggedRows { row, expected, actual } => {
+                write!(f, "row {} has {} elements, expected {}", row, actual, expected)
+            }
+            MatrixError::DimensionMismatch { left, right, op } => match op {
+                "subtract" => write!(f, "cannot subtract {}x{} matrix from {}x{} matrix: dimensions differ",
+                                     right.0, right.1, left.0, left.1),
+                "multiply" => write!(f, "cannot multiply {}x{} matrix by {}x{} matrix: inner dimensions differ",
+                                     left.0, left.1, right.0, right.1),
+                "select" => write!(f, "cannot select from {}x{} matrix with {}x{} mask: dimensions differ",
+                                   right.0, right.1, left.0, left.1),
+                "stack horizontally" => write!(f, "cannot stack {}x{} matrix and {}x{} matrix horizontally: row counts differ",
+                                               left.0, left.1, right.0, right.1),
+                "copy into" => write!(f, "cannot copy {}x{} matrix into {}x{} view: dimensions differ",
+                                      right.0, right.1, left.0, left.1),
+                "stack vertically" => write!(f, "cannot stack {}x{} matrix and {}x{} matrix vertically: column counts differ",
+                                             left.0, left.1, right.0, right.1),
+                _ => write!(f, "cannot {} {}x{} matrix and {}x{} matrix: dimensions differ",
+                            op, left.0, left.1, right.0, right.1),
+            },
+            MatrixError::NotSquare { shape, op } => {
+                write!(f, "cannot {} {}x{} matrix: matrix is not square", op, shape.0, shape.1)
+            }
+            MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            MatrixError::NotSymmetric { at, op } => {
+                write!(f, "cannot {} matrix: not symmetric at {:?}", op, at)
+            }
+            MatrixError::RankDeficient => write!(f, "matrix does not have full column rank"),
+            MatrixError::NoConvergence { iterations, residual, .. } => {
+                write!(f, "failed to converge after {} iterations (residual {:e})", iterations, residual)
+            }
+            MatrixError::ConversionFailed { at, ref value } => {
+                write!(f, "cannot convert element {} at {:?} to the target type", value, at)
+            }
+            MatrixError::IndexOutOfBounds { index, shape, op } => {
+                write!(f, "cannot {} at index {} of {}x{} matrix: index out of bounds", op, index, shape.0, shape.1)
+            }
+            MatrixError::BlockShape { block, shape, expected } => {
+                write!(f, "block {:?} is {}x{}, but its block row and column need {}x{}",
+                       block, shape.0, shape.1, expected.0, expected.1)
+            }
+        }
+    }
+}
+
+impl error::Error for MatrixError {}
@@ -0,0 +1,100 @@
+//! Element-wise mathematical functions and predicates for `Matrix<f32>` and
+//! `Matrix<f64>`. Each function applies the primitive function of the same
+//! name to every element and returns a new matrix of the same shape, while
+//! the `any_nan` and `all_finite` predicates return a single `bool`.
+
+use std::convert::TryFrom;
+
+use Matrix;
+
+macro_rules! impl_float_math {
+    ($($t:ty)*) => ($(
+        impl Matrix<$t> {
+            /// Returns whether any element is NaN, stopping at the first one.
+            pub fn any_nan(&self) -> bool {
+                self.any(|value| value.is_nan())
+            }
+
+            /// Returns whether every element is finite, that is neither
+            /// infinite nor NaN, stopping at the first that is not.
+            pub fn all_finite(&self) -> bool {
+                self.all(|value| value.is_finite())
+            }
+
+            /// Returns the element-wise square root. Negative elements give
+            /// NaN, as `sqrt` does on the scalar.
+            pub fn sqrt(&self) -> Matrix<$t> {
+                self.map(|&value| value.sqrt())
+            }
+
+            /// Returns the element-wise exponential `e^x`.
+            pub fn exp(&self) -> Matrix<$t> {
+                self.map(|&value| value.exp())
+            }
+
+            /// Returns the element-wise natural logarithm. Zero gives negative
+            /// infinity and negative elements give NaN, as `ln` does on the
+            /// scalar.
+            pub fn ln(&self) -> Matrix<$t> {
+                self.map(|&value| value.ln())
+            }
+
+            /// Raises every element to the integer power `n`. This is the
+            /// element-wise power; `pow` is the matrix power.
+            pub fn powi(&self, n: i32) -> Matrix<$t> {
+                self.map(|&value| value.powi(n))
+            }
+
+            /// Raises every element to the float power `p`. Negative elements
+            /// with a non-integer `p` give NaN, as `powf` does on the scalar.
+            pub fn powf(&self, p: $t) -> Matrix<$t> {
+                self.map(|&value| value.powf(p))
+            }
+
+            /// Rounds every element to the nearest integer. Halves round away
+            /// from zero, as `round` does on the scalar, so `-2.5` becomes
+            /// `-3.0` rather than the even `-2.0`.
+            pub fn round(&self) -> Matrix<$t> {
+                self.map(|&value| value.round())
+            }
+
+            /// Rounds every element down to the nearest integer.
+            pub fn floor(&self) -> Matrix<$t> {
+                self.map(|&value| value.floor())
+            }
+
+            /// Rounds every element up to the nearest integer.
+            pub fn ceil(&self) -> Matrix<$t> {
+                self.map(|&value| value.ceil())
+            }
+
+            /// Rounds every element toward zero, dropping its fractional part.
+            pub fn trunc(&self) -> Matrix<$t> {
+                self.map(|&value| value.trunc())
+            }
+
+            /// Rounds every element to `decimals` decimal places, with halves
+            /// rounding away from zero as in `round`. The element is scaled by
+            /// `10^decimals` in floating point first, so a value like `2.675`,
+            /// which is not exactly representable, may land on either side of
+            /// the tie; use this for display and comparison rather than exact
+            /// decimal arithmetic. Elements without a fractional part, which
+            /// includes every float too large to have one, are returned
+            /// unchanged, as are elements too large to scale by `10^decimals`.
+            pub fn round_to(&self, decimals: u32) -> Matrix<$t> {
+                let exp = TryFrom::try_from(decimals).unwrap_or(i32::MAX);
+                let scale = (10.0 as $t).powi(exp);
+                self.map(|&value| {
+                    let scaled = value * scale;
+                    if value.fract() == 0.0 || !scaled.is_finite() {
+                        value
+                    } else {
+                        scaled.round() / scale
+                    }
+                })
+            }
+        }
+    )*)
+}
+
+impl_float_math! { f32 f64 }
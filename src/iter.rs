@@ -0,0 +1,384 @@
+use std::{iter, mem, slice, vec};
+
+use Matrix;
+
+/// Iterator over the rows of a matrix as slices, created by `Matrix::rows`.
+#[derive(Debug, Clone)]
+pub struct Rows<'a, T: 'a> {
+    data: &'a [T],
+    col: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Rows<'a, T> {
+    pub(crate) fn new(data: &'a [T], row: usize, col: usize) -> Rows<'a, T> {
+        Rows { data, col, front: 0, back: row }
+    }
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.front == self.back {
+            return None;
+        }
+        let start = self.front * self.col;
+        self.front += 1;
+        Some(&self.data[start..start + self.col])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Rows<'a, T> {
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let start = self.back * self.col;
+        Some(&self.data[start..start + self.col])
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Rows<'a, T> {}
+
+/// Iterator over the rows of a matrix as mutable slices, created by `Matrix::rows_mut`.
+#[derive(Debug)]
+pub struct RowsMut<'a, T: 'a> {
+    data: &'a mut [T],
+    col: usize,
+    len: usize,
+}
+
+impl<'a, T> RowsMut<'a, T> {
+    pub(crate) fn new(data: &'a mut [T], row: usize, col: usize) -> RowsMut<'a, T> {
+        RowsMut { data, col, len: row }
+    }
+}
+
+impl<'a, T> Iterator for RowsMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.len == 0 {
+            return None;
+        }
+        let data = mem::take(&mut self.data);
+        let (head, tail) = data.split_at_mut(self.col);
+        self.data = tail;
+        self.len -= 1;
+        Some(head)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RowsMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        if self.len == 0 {
+            return None;
+        }
+        let data = mem::take(&mut self.data);
+        let split = data.len() - self.col;
+        let (head, tail) = data.split_at_mut(split);
+        self.data = head;
+        self.len -= 1;
+        Some(tail)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RowsMut<'a, T> {}
+
+/// Iterator over the columns of a matrix, created by `Matrix::columns`.
+/// Each item is a `Column` walking one column from top to bottom.
+#[derive(Debug, Clone)]
+pub struct Columns<'a, T: 'a> {
+    data: &'a [T],
+    row: usize,
+    col: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Columns<'a, T> {
+    pub(crate) fn new(data: &'a [T], row: usize, col: usize) -> Columns<'a, T> {
+        Columns { data, row, col, front: 0, back: col }
+    }
+}
+
+impl<'a, T> Iterator for Columns<'a, T> {
+    type Item = Column<'a, T>;
+
+    fn next(&mut self) -> Option<Column<'a, T>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(Column::new(self.data, self.row, self.col, self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Columns<'a, T> {
+    fn next_back(&mut self) -> Option<Column<'a, T>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(Column::new(self.data, self.row, self.col, self.back))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Columns<'a, T> {}
+
+/// Iterator over the elements of a single column, walking `data` with a
+/// stride of `col`.
+#[derive(Debug, Clone)]
+pub struct Column<'a, T: 'a> {
+    data: &'a [T],
+    stride: usize,
+    offset: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Column<'a, T> {
+    fn new(data: &'a [T], row: usize, col: usize, j: usize) -> Column<'a, T> {
+        Column { data, stride: col, offset: j, front: 0, back: row }
+    }
+}
+
+impl<'a, T> Iterator for Column<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+        let index = self.front * self.stride + self.offset;
+        self.front += 1;
+        Some(&self.data[index])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Column<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(&self.data[self.back * self.stride + self.offset])
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Column<'a, T> {}
+
+/// Iterator over references to the elements of a matrix in row-major order,
+/// created by `Matrix::iter`.
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T: 'a> {
+    inner: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(crate) fn new(data: &'a [T]) -> Iter<'a, T> {
+        Iter { inner: data.iter() }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+/// Iterator over mutable references to the elements of a matrix in row-major
+/// order, created by `Matrix::iter_mut`.
+#[derive(Debug)]
+pub struct IterMut<'a, T: 'a> {
+    inner: slice::IterMut<'a, T>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    pub(crate) fn new(data: &'a mut [T]) -> IterMut<'a, T> {
+        IterMut { inner: data.iter_mut() }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+/// Owning iterator over the elements of a matrix in row-major order, created
+/// by `Matrix::into_iter`.
+#[derive(Debug, Clone)]
+pub struct IntoIter<T> {
+    inner: vec::IntoIter<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for Matrix<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consumes the matrix, yielding its elements in row-major order.
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { inner: self.data.into_iter() }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Matrix<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        Iter::new(&self.data)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Matrix<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        IterMut::new(&mut self.data)
+    }
+}
+
+/// Iterator over `(row, col, &value)` triples in row-major order, created by
+/// `Matrix::indexed_iter`.
+#[derive(Debug, Clone)]
+pub struct IndexedIter<'a, T: 'a> {
+    inner: iter::Enumerate<slice::Iter<'a, T>>,
+    col: usize,
+}
+
+impl<'a, T> IndexedIter<'a, T> {
+    pub(crate) fn new(data: &'a [T], col: usize) -> IndexedIter<'a, T> {
+        IndexedIter { inner: data.iter().enumerate(), col }
+    }
+}
+
+impl<'a, T> Iterator for IndexedIter<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<(usize, usize, &'a T)> {
+        let col = self.col;
+        self.inner.next().map(|(i, value)| (i / col, i % col, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IndexedIter<'a, T> {
+    fn next_back(&mut self) -> Option<(usize, usize, &'a T)> {
+        let col = self.col;
+        self.inner.next_back().map(|(i, value)| (i / col, i % col, value))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IndexedIter<'a, T> {}
+
+/// Iterator over `(row, col, &mut value)` triples in row-major order, created
+/// by `Matrix::indexed_iter_mut`.
+#[derive(Debug)]
+pub struct IndexedIterMut<'a, T: 'a> {
+    inner: iter::Enumerate<slice::IterMut<'a, T>>,
+    col: usize,
+}
+
+impl<'a, T> IndexedIterMut<'a, T> {
+    pub(crate) fn new(data: &'a mut [T], col: usize) -> IndexedIterMut<'a, T> {
+        IndexedIterMut { inner: data.iter_mut().enumerate(), col }
+    }
+}
+
+impl<'a, T> Iterator for IndexedIterMut<'a, T> {
+    type Item = (usize, usize, &'a mut T);
+
+    fn next(&mut self) -> Option<(usize, usize, &'a mut T)> {
+        let col = self.col;
+        self.inner.next().map(|(i, value)| (i / col, i % col, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IndexedIterMut<'a, T> {
+    fn next_back(&mut self) -> Option<(usize, usize, &'a mut T)> {
+        let col = self.col;
+        self.inner.next_back().map(|(i, value)| (i / col, i % col, value))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IndexedIterMut<'a, T> {}
@@ -1,6 +1,28 @@
-use std::{ops, fmt};
+use std::{ops, fmt, mem};
+use std::iter::Sum;
+use std::convert::TryFrom;
 
-#[derive(PartialEq, Debug)]
+#[cfg(feature = "rand")]
+extern crate rand;
+
+mod error;
+mod float;
+mod iter;
+mod linalg;
+#[cfg(feature = "rand")]
+mod random;
+mod scalar;
+mod stats;
+mod view;
+
+pub use error::MatrixError;
+pub use scalar::{Divisor, Signed};
+#[cfg(feature = "rand")]
+pub use random::SampleNormal;
+pub use iter::{Iter, IterMut, IntoIter, IndexedIter, IndexedIterMut, Rows, RowsMut, Columns, Column};
+pub use view::{MatrixView, MatrixViewMut, ViewRows};
+
+#[derive(PartialEq, Debug, Clone)]
 pub struct Matrix<T> {
     /// Stores elements in [row-major order](https://en.wikipedia.org/wiki/Row-major_order)
     data: Vec<T>,
@@ -10,68 +32,1409 @@ pub struct Matrix<T> {
     col: usize,
 }
 
-impl<T: Copy> Matrix<T> {
-    /// Creates a new matrix of `row` rows and `col` columns, and initializes
-    /// the matrix with the elements in `values` in row-major order.
-    pub fn new(row: usize, col: usize, values: &[T]) -> Matrix<T> {
-        Matrix{ data: values.to_vec(), row: row, col: col }
+impl<T: Copy> Matrix<T> {
+    /// Creates a new matrix of `row` rows and `col` columns, and initializes
+    /// the matrix with the elements in `values` in row-major order.
+    ///
+    /// `values` must hold exactly `row * col` elements; this is only checked
+    /// in debug builds. Use `try_new` to validate the length at runtime.
+    pub fn new(row: usize, col: usize, values: &[T]) -> Matrix<T> {
+        debug_assert_eq!(values.len(), row * col, "{}x{} matrix needs {} values", row, col, row * col);
+        Matrix{ data: values.to_vec(), row, col }
+    }
+
+    /// Like `new`, but returns `MatrixError::LengthMismatch` if
+    /// `values.len() != row * col`.
+    pub fn try_new(row: usize, col: usize, values: &[T]) -> Result<Matrix<T>, MatrixError> {
+        if values.len() != row * col {
+            return Err(MatrixError::LengthMismatch { expected: row * col, actual: values.len() });
+        }
+        Ok(Matrix{ data: values.to_vec(), row, col })
+    }
+
+    /// Creates a new, empty matrix of `row` rows and `col` columns.
+    /// `data` contains no element.
+    pub fn new_empty(row: usize, col: usize) -> Matrix<T> {
+        Matrix{ data: Vec::new(), row, col }
+    }
+
+    /// Creates a new matrix of `row` rows and `col` columns with every
+    /// element set to `value`.
+    pub fn filled(row: usize, col: usize, value: T) -> Matrix<T> {
+        Matrix{ data: vec![value; row * col], row, col }
+    }
+
+    /// Creates a new matrix of `row` rows and `col` columns whose element at
+    /// `(i, j)` is `f(i, j)`. `f` is called once per element in row-major order.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(row: usize, col: usize, mut f: F) -> Matrix<T> {
+        let mut data = Vec::with_capacity(row * col);
+        for i in 0..row {
+            for j in 0..col {
+                data.push(f(i, j));
+            }
+        }
+        Matrix{ data, row, col }
+    }
+
+    /// Creates a matrix from an iterator of rows. The width is taken from the
+    /// first row, and zero rows yield a 0x0 matrix. Returns
+    /// `MatrixError::RaggedRows` naming the first row whose length differs.
+    pub fn from_rows<I, R>(rows: I) -> Result<Matrix<T>, MatrixError>
+        where I: IntoIterator<Item = R>, R: AsRef<[T]>
+    {
+        let mut data = Vec::new();
+        let mut row = 0;
+        let mut col = 0;
+        for values in rows {
+            let values = values.as_ref();
+            if row == 0 {
+                col = values.len();
+            } else if values.len() != col {
+                return Err(MatrixError::RaggedRows { row, expected: col, actual: values.len() });
+            }
+            data.extend_from_slice(values);
+            row += 1;
+        }
+        Ok(Matrix{ data, row, col })
+    }
+
+    /// Returns a shared reference to `data`
+    pub fn data(&self) -> &Vec<T> {
+        &self.data
+    }
+
+    /// Returns a mutable reference to `data`
+    pub fn mut_data(&mut self) -> &mut Vec<T> {
+        &mut self.data
+    }
+
+    /// Returns the number of rows and columns in the first and second
+    /// elements of the tuple, respectively.
+    pub fn size(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// Returns `true` if `self` has as many rows as columns.
+    pub fn is_square(&self) -> bool {
+        self.row == self.col
+    }
+
+    /// Returns `true` if `self` is square and equal to its transpose. Use
+    /// `is_symmetric_with_tol` to allow for rounding error in float matrices.
+    pub fn is_symmetric(&self) -> bool
+        where T: PartialEq
+    {
+        self.is_square() && (0..self.row).all(|i| {
+            ((i + 1)..self.col).all(|j| self.data[i * self.col + j] == self.data[j * self.col + i])
+        })
+    }
+
+    /// Returns a shared reference to the element at `row`, `col`, or `None`
+    /// if either index is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.row && col < self.col {
+            self.data.get(row * self.col + col)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `row`, `col`, or `None`
+    /// if either index is out of bounds.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.row && col < self.col {
+            self.data.get_mut(row * self.col + col)
+        } else {
+            None
+        }
+    }
+
+    /// Returns row `i` as a slice. If `i >= self.row`, panic.
+    pub fn row(&self, i: usize) -> &[T] {
+        &self[i]
+    }
+
+    /// Returns a copy of column `j`, top to bottom. If `j >= self.col`, panic.
+    pub fn column(&self, j: usize) -> Vec<T> {
+        if j >= self.col {
+            panic!("column index {} out of bounds for {}x{} matrix", j, self.row, self.col);
+        }
+        self.data.iter().skip(j).step_by(self.col).cloned().collect()
+    }
+
+    /// Returns an iterator over the rows of the matrix, each as a slice.
+    pub fn rows(&self) -> Rows<'_, T> {
+        Rows::new(&self.data, self.row, self.col)
+    }
+
+    /// Returns an iterator over the rows of the matrix, each as a mutable slice.
+    pub fn rows_mut(&mut self) -> RowsMut<'_, T> {
+        RowsMut::new(&mut self.data, self.row, self.col)
+    }
+
+    /// Returns an iterator over the columns of the matrix. Each column is
+    /// itself an iterator over references to its elements, top to bottom.
+    pub fn columns(&self) -> Columns<'_, T> {
+        Columns::new(&self.data, self.row, self.col)
+    }
+
+    /// Returns an iterator over references to the elements in row-major order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(&self.data)
+    }
+
+    /// Returns an iterator over mutable references to the elements in row-major order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(&mut self.data)
+    }
+
+    /// Returns an iterator over `(row, col, &value)` in row-major order, with
+    /// indices matching `self[(row, col)]`.
+    pub fn indexed_iter(&self) -> IndexedIter<'_, T> {
+        IndexedIter::new(&self.data, self.col)
+    }
+
+    /// Returns an iterator over `(row, col, &mut value)` in row-major order,
+    /// with indices matching `self[(row, col)]`.
+    pub fn indexed_iter_mut(&mut self) -> IndexedIterMut<'_, T> {
+        IndexedIterMut::new(&mut self.data, self.col)
+    }
+
+    /// Returns a matrix of the same size whose elements are `f` applied to
+    /// the elements of `self`, in row-major order. The element type may change.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Matrix<U> {
+        Matrix{ data: self.data.iter().map(f).collect(), row: self.row, col: self.col }
+    }
+
+    /// Returns the running totals along each row: element `(i, j)` of the
+    /// result is the sum of elements `(i, 0..=j)` of `self`.
+    pub fn cumsum_rows(&self) -> Matrix<T>
+        where T: ops::Add<Output = T>
+    {
+        let mut data = self.data.clone();
+        for row in data.chunks_mut(self.col.max(1)) {
+            for j in 1..row.len() {
+                row[j] = row[j - 1] + row[j];
+            }
+        }
+        Matrix{ data, row: self.row, col: self.col }
+    }
+
+    /// Returns the running totals down each column: element `(i, j)` of the
+    /// result is the sum of elements `(0..=i, j)` of `self`. The data is
+    /// walked once in row-major order, adding the previous row into each row.
+    pub fn cumsum_cols(&self) -> Matrix<T>
+        where T: ops::Add<Output = T>
+    {
+        let mut data = self.data.clone();
+        for index in self.col..data.len() {
+            data[index] = data[index - self.col] + data[index];
+        }
+        Matrix{ data, row: self.row, col: self.col }
+    }
+
+    /// Returns the differences between adjacent elements of each row, a
+    /// `row` x `(col - 1)` matrix with element `(i, j)` equal to
+    /// `self[(i, j + 1)] - self[(i, j)]`, like NumPy's `diff` along axis 1.
+    /// A matrix with at most one column yields `row` x `0`.
+    pub fn diff_rows(&self) -> Matrix<T>
+        where T: ops::Sub<Output = T>
+    {
+        let col = self.col.saturating_sub(1);
+        Matrix::from_fn(self.row, col, |i, j| self.data[i * self.col + j + 1] - self.data[i * self.col + j])
+    }
+
+    /// Returns the differences between adjacent elements of each column, a
+    /// `(row - 1)` x `col` matrix with element `(i, j)` equal to
+    /// `self[(i + 1, j)] - self[(i, j)]`, like NumPy's `diff` along axis 0.
+    /// A matrix with at most one row yields `0` x `col`.
+    pub fn diff_cols(&self) -> Matrix<T>
+        where T: ops::Sub<Output = T>
+    {
+        let row = self.row.saturating_sub(1);
+        let data = self.data.iter().skip(self.col).zip(self.data.iter())
+            .map(|(&next, &value)| next - value)
+            .collect();
+        Matrix{ data, row, col: self.col }
+    }
+
+    /// Returns a copy with every element clipped into `lo..=hi`. Elements
+    /// that compare neither below `lo` nor above `hi`, such as NaN, are kept
+    /// as they are. `lo <= hi` is checked in debug builds.
+    pub fn clamp(&self, lo: T, hi: T) -> Matrix<T>
+        where T: PartialOrd
+    {
+        let mut clamped = self.clone();
+        clamped.clamp_mut(lo, hi);
+        clamped
+    }
+
+    /// Clips every element into `lo..=hi` in place, as `clamp` does.
+    pub fn clamp_mut(&mut self, lo: T, hi: T)
+        where T: PartialOrd
+    {
+        debug_assert!(lo <= hi, "clamp bounds are reversed");
+        for value in &mut self.data {
+            if *value < lo {
+                *value = lo;
+            } else if *value > hi {
+                *value = hi;
+            }
+        }
+    }
+
+    /// Returns a copy with every element replaced by its absolute value. For
+    /// integer matrices the most negative value overflows as `i32::abs` does;
+    /// see `checked_abs`.
+    pub fn abs(&self) -> Matrix<T>
+        where T: Signed
+    {
+        self.map(|&value| value.abs())
+    }
+
+    /// Returns a copy with every element replaced by its absolute value, or
+    /// `None` if any element's absolute value overflows.
+    pub fn checked_abs(&self) -> Option<Matrix<T>>
+        where T: Signed
+    {
+        let data = self.data.iter().map(|&value| value.checked_abs()).collect::<Option<Vec<T>>>()?;
+        Some(Matrix{ data, row: self.row, col: self.col })
+    }
+
+    /// Returns the smallest element, or `None` if the matrix is empty. Ties
+    /// resolve to the first occurrence in row-major order. Elements that are
+    /// not comparable with themselves (NaN) are skipped, so a float matrix
+    /// that is entirely NaN also yields `None`.
+    pub fn min(&self) -> Option<T>
+        where T: PartialOrd
+    {
+        self.argmin().map(|(i, j)| self.data[i * self.col + j])
+    }
+
+    /// Returns the largest element, or `None` if the matrix is empty. Ties
+    /// and NaN are handled as in `min`.
+    pub fn max(&self) -> Option<T>
+        where T: PartialOrd
+    {
+        self.argmax().map(|(i, j)| self.data[i * self.col + j])
+    }
+
+    /// Returns the position `(row, col)` of the smallest element, with ties
+    /// and NaN handled as in `min`.
+    pub fn argmin(&self) -> Option<(usize, usize)>
+        where T: PartialOrd
+    {
+        self.position_by(|value, best| value < best)
+    }
+
+    /// Returns the position `(row, col)` of the largest element, with ties
+    /// and NaN handled as in `min`.
+    pub fn argmax(&self) -> Option<(usize, usize)>
+        where T: PartialOrd
+    {
+        self.position_by(|value, best| value > best)
+    }
+
+    /// Returns the position of the first element that no later element
+    /// beats, where `beats(value, best)` decides whether `value` replaces the
+    /// current best.
+    fn position_by<F>(&self, beats: F) -> Option<(usize, usize)>
+        where T: PartialOrd, F: Fn(&T, &T) -> bool
+    {
+        best_index(self.data.iter(), &beats).map(|index| (index / self.col, index % self.col))
+    }
+
+    /// Returns, for each row, the column of its largest element, with ties
+    /// and NaN handled as in `min`; a row that is entirely NaN yields `0`.
+    /// If `self` has rows but no columns, panic.
+    pub fn row_argmax(&self) -> Vec<usize>
+        where T: PartialOrd
+    {
+        self.row_positions_by("take the row-wise argmax of", |value, best| value > best)
+    }
+
+    /// Returns, for each row, the column of its smallest element, with ties
+    /// and NaN handled as in `row_argmax`.
+    pub fn row_argmin(&self) -> Vec<usize>
+        where T: PartialOrd
+    {
+        self.row_positions_by("take the row-wise argmin of", |value, best| value < best)
+    }
+
+    /// Returns, for each column, the row of its largest element, with ties
+    /// and NaN handled as in `row_argmax`. If `self` has columns but no rows,
+    /// panic.
+    pub fn col_argmax(&self) -> Vec<usize>
+        where T: PartialOrd
+    {
+        self.col_positions_by("take the column-wise argmax of", |value, best| value > best)
+    }
+
+    /// Returns, for each column, the row of its smallest element, with ties
+    /// and NaN handled as in `row_argmax`.
+    pub fn col_argmin(&self) -> Vec<usize>
+        where T: PartialOrd
+    {
+        self.col_positions_by("take the column-wise argmin of", |value, best| value < best)
+    }
+
+    /// Applies `best_index` to every row. Panics for `op` if rows are empty.
+    fn row_positions_by<F>(&self, op: &str, beats: F) -> Vec<usize>
+        where T: PartialOrd, F: Fn(&T, &T) -> bool
+    {
+        if self.col == 0 && self.row > 0 {
+            panic!("cannot {} {}x{} matrix: rows are empty", op, self.row, self.col);
+        }
+        self.rows().map(|row| best_index(row.iter(), &beats).unwrap_or(0)).collect()
+    }
+
+    /// Finds the best row of every column in one row-major pass over the
+    /// data, keeping the current best row per column. Panics for `op` if
+    /// columns are empty.
+    fn col_positions_by<F>(&self, op: &str, beats: F) -> Vec<usize>
+        where T: PartialOrd, F: Fn(&T, &T) -> bool
+    {
+        if self.row == 0 && self.col > 0 {
+            panic!("cannot {} {}x{} matrix: columns are empty", op, self.row, self.col);
+        }
+        let mut best: Vec<Option<usize>> = vec![None; self.col];
+        for (i, row) in self.rows().enumerate() {
+            for (j, value) in row.iter().enumerate() {
+                if value.partial_cmp(value).is_none() {
+                    continue;
+                }
+                match best[j] {
+                    Some(k) if !beats(value, &self.data[k * self.col + j]) => {}
+                    _ => best[j] = Some(i),
+                }
+            }
+        }
+        best.into_iter().map(|i| i.unwrap_or(0)).collect()
+    }
+
+    /// Returns a matrix of the same size with every element converted to `U`
+    /// through its lossless `From` conversion.
+    ///
+    /// ```
+    /// use your::Matrix;
+    ///
+    /// let pixels: Matrix<u8> = Matrix::new(2, 2, &[0, 64, 128, 255]);
+    /// let intensities = pixels.cast::<f32>().map(|&p| p / 255.0);
+    /// assert_eq!(intensities[(1, 1)], 1.0);
+    /// ```
+    pub fn cast<U: From<T>>(&self) -> Matrix<U> {
+        self.map(|&value| U::from(value))
+    }
+
+    /// Returns a matrix of the same size with every element converted to `U`
+    /// through `TryFrom`, or `MatrixError::ConversionFailed` with the position
+    /// and value of the first element, in row-major order, that does not fit.
+    pub fn try_cast<U: TryFrom<T>>(&self) -> Result<Matrix<U>, MatrixError>
+        where T: fmt::Debug
+    {
+        let mut data = Vec::with_capacity(self.data.len());
+        for (i, j, &value) in self.indexed_iter() {
+            match U::try_from(value) {
+                Ok(converted) => data.push(converted),
+                Err(_) => return Err(MatrixError::ConversionFailed { at: (i, j), value: format!("{:?}", value) }),
+            }
+        }
+        Ok(Matrix{ data, row: self.row, col: self.col })
+    }
+
+    /// Returns the matrix whose elements are `f` applied to corresponding
+    /// elements of `self` and `other`, or `MatrixError::DimensionMismatch`
+    /// if the two matrices differ in shape.
+    pub fn zip_with<U, V, F>(&self, other: &Matrix<U>, mut f: F) -> Result<Matrix<V>, MatrixError>
+        where F: FnMut(&T, &U) -> V
+    {
+        self.check_same_size(other, "combine")?;
+        let data = self.data.iter().zip(other.data.iter()).map(|(a, b)| f(a, b)).collect();
+        Ok(Matrix{ data, row: self.row, col: self.col })
+    }
+
+    /// Applies `f` to every element in place, in row-major order.
+    pub fn apply<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.data.iter_mut().for_each(f);
+    }
+
+    /// Applies `f` to every element in place together with its row and
+    /// column, in row-major order.
+    pub fn apply_indexed<F: FnMut(usize, usize, &mut T)>(&mut self, mut f: F) {
+        for (i, j, value) in self.indexed_iter_mut() {
+            f(i, j, value);
+        }
+    }
+
+    /// Returns the mask that is `true` where `self` is less than `rhs`, or
+    /// `MatrixError::DimensionMismatch` if the two matrices differ in shape.
+    /// Comparisons involving NaN are `false`, here and in the other
+    /// comparison methods.
+    pub fn lt(&self, rhs: &Matrix<T>) -> Result<Matrix<bool>, MatrixError>
+        where T: PartialOrd
+    {
+        self.compare(rhs, |a, b| a < b)
+    }
+
+    /// Returns the mask that is `true` where `self` is less than or equal to
+    /// `rhs`, or `MatrixError::DimensionMismatch` if the shapes differ.
+    pub fn le(&self, rhs: &Matrix<T>) -> Result<Matrix<bool>, MatrixError>
+        where T: PartialOrd
+    {
+        self.compare(rhs, |a, b| a <= b)
+    }
+
+    /// Returns the mask that is `true` where `self` is greater than `rhs`, or
+    /// `MatrixError::DimensionMismatch` if the shapes differ.
+    pub fn gt(&self, rhs: &Matrix<T>) -> Result<Matrix<bool>, MatrixError>
+        where T: PartialOrd
+    {
+        self.compare(rhs, |a, b| a > b)
+    }
+
+    /// Returns the mask that is `true` where `self` is greater than or equal
+    /// to `rhs`, or `MatrixError::DimensionMismatch` if the shapes differ.
+    pub fn ge(&self, rhs: &Matrix<T>) -> Result<Matrix<bool>, MatrixError>
+        where T: PartialOrd
+    {
+        self.compare(rhs, |a, b| a >= b)
+    }
+
+    /// Returns the mask that is `true` where `self` equals `rhs`, or
+    /// `MatrixError::DimensionMismatch` if the shapes differ. Unlike `==`,
+    /// which compares whole matrices, this compares element by element.
+    pub fn eq_elem(&self, rhs: &Matrix<T>) -> Result<Matrix<bool>, MatrixError>
+        where T: PartialEq
+    {
+        self.compare(rhs, |a, b| a == b)
+    }
+
+    /// Returns the mask that is `true` where an element is less than `rhs`.
+    pub fn lt_scalar(&self, rhs: T) -> Matrix<bool>
+        where T: PartialOrd
+    {
+        self.map(|&value| value < rhs)
+    }
+
+    /// Returns the mask that is `true` where an element is less than or equal
+    /// to `rhs`.
+    pub fn le_scalar(&self, rhs: T) -> Matrix<bool>
+        where T: PartialOrd
+    {
+        self.map(|&value| value <= rhs)
+    }
+
+    /// Returns the mask that is `true` where an element is greater than `rhs`.
+    pub fn gt_scalar(&self, rhs: T) -> Matrix<bool>
+        where T: PartialOrd
+    {
+        self.map(|&value| value > rhs)
+    }
+
+    /// Returns the mask that is `true` where an element is greater than or
+    /// equal to `rhs`.
+    pub fn ge_scalar(&self, rhs: T) -> Matrix<bool>
+        where T: PartialOrd
+    {
+        self.map(|&value| value >= rhs)
+    }
+
+    /// Returns the mask that is `true` where an element equals `rhs`.
+    pub fn eq_scalar(&self, rhs: T) -> Matrix<bool>
+        where T: PartialEq
+    {
+        self.map(|&value| value == rhs)
+    }
+
+    /// Returns the number of elements for which `pred` holds.
+    pub fn count_where<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.data.iter().filter(|value| pred(value)).count()
+    }
+
+    /// Returns the number of elements in each row for which `pred` holds,
+    /// one count per row.
+    pub fn row_counts_where<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Vec<usize> {
+        self.rows().map(|row| row.iter().filter(|value| pred(value)).count()).collect()
+    }
+
+    /// Returns the number of elements in each column for which `pred` holds,
+    /// one count per column. The data is walked once in row-major order.
+    pub fn col_counts_where<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Vec<usize> {
+        let mut counts = vec![0; self.col];
+        for row in self.rows() {
+            for (count, value) in counts.iter_mut().zip(row) {
+                if pred(value) {
+                    *count += 1;
+                }
+            }
+        }
+        counts
+    }
+
+    /// Returns how often each value occurs in a matrix of non-negative
+    /// integers: element `v` of the result counts the elements equal to `v`,
+    /// for every `v` up to the largest element. An empty matrix gives an
+    /// empty vector. The result has `max + 1` entries, so this is meant for
+    /// small values such as labels. If an element is negative or does not
+    /// fit in `usize`, panic.
+    pub fn bincount(&self) -> Vec<usize>
+        where usize: TryFrom<T>, T: fmt::Debug
+    {
+        let mut counts = Vec::new();
+        for (i, j, &value) in self.indexed_iter() {
+            let bin = match usize::try_from(value) {
+                Ok(bin) => bin,
+                Err(_) => panic!("cannot bincount element {:?} at {:?}: not a non-negative integer", value, (i, j)),
+            };
+            if bin >= counts.len() {
+                counts.resize(bin + 1, 0);
+            }
+            counts[bin] += 1;
+        }
+        counts
+    }
+
+    /// Returns the `(row, col)` index of every element for which `pred`
+    /// holds, in row-major order. Each index can be passed straight to
+    /// `m[(row, col)]`.
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Vec<(usize, usize)> {
+        self.indexed_iter().filter(|&(_, _, value)| pred(value)).map(|(i, j, _)| (i, j)).collect()
+    }
+
+    /// Returns the `(row, col)` index of the first element, in row-major
+    /// order, for which `pred` holds, or `None` if there is none. Stops at
+    /// the first match.
+    pub fn position<F: FnMut(&T) -> bool>(&self, pred: F) -> Option<(usize, usize)> {
+        self.data.iter().position(pred).map(|index| (index / self.col, index % self.col))
+    }
+
+    /// Returns the matrix that takes each element from `if_true` where `mask`
+    /// is `true` and from `if_false` elsewhere, like NumPy's `where`. Returns
+    /// `MatrixError::DimensionMismatch`, with the mask as the left shape, if
+    /// either matrix differs in shape from `mask`.
+    pub fn select(mask: &Matrix<bool>, if_true: &Matrix<T>, if_false: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        mask.check_same_size(if_true, "select")?;
+        mask.check_same_size(if_false, "select")?;
+        let data = mask.data.iter().zip(if_true.data.iter().zip(if_false.data.iter()))
+            .map(|(&pick, (&a, &b))| if pick { a } else { b })
+            .collect();
+        Ok(Matrix{ data, row: mask.row, col: mask.col })
+    }
+
+    /// Returns whether `pred` holds for at least one element, testing them in
+    /// row-major order and stopping at the first match. An empty matrix has
+    /// no such element, so the result is `false`.
+    pub fn any<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.data.iter().any(pred)
+    }
+
+    /// Returns whether `pred` holds for every element, testing them in
+    /// row-major order and stopping at the first failure. An empty matrix
+    /// has no counterexample, so the result is `true`.
+    pub fn all<F: FnMut(&T) -> bool>(&self, pred: F) -> bool {
+        self.data.iter().all(pred)
+    }
+
+    /// Returns a copy of the main diagonal. For rectangular matrices the
+    /// diagonal stops at the shorter dimension, so it has `min(row, col)` elements.
+    pub fn diagonal(&self) -> Vec<T> {
+        (0..self.row.min(self.col)).map(|i| self.data[i * self.col + i]).collect()
+    }
+
+    /// Overwrites the main diagonal with `values`. Returns
+    /// `MatrixError::LengthMismatch` and leaves the matrix untouched if
+    /// `values.len() != min(row, col)`.
+    pub fn set_diagonal(&mut self, values: &[T]) -> Result<(), MatrixError> {
+        let len = self.row.min(self.col);
+        if values.len() != len {
+            return Err(MatrixError::LengthMismatch { expected: len, actual: values.len() });
+        }
+        for (i, &value) in values.iter().enumerate() {
+            self.data[i * self.col + i] = value;
+        }
+        Ok(())
+    }
+
+    /// Returns the sum of the main diagonal. If `self` is not square, panic.
+    pub fn trace(&self) -> T
+        where T: Sum
+    {
+        if let Err(err) = self.check_square("take the trace of") {
+            panic!("{}", err);
+        }
+        self.diagonal().into_iter().sum()
+    }
+
+    /// Returns a new `col` x `row` matrix whose element `(j, i)` is element
+    /// `(i, j)` of `self`.
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut transposed = Matrix::new_empty(self.col, self.row);
+        for j in 0..self.col {
+            for i in 0..self.row {
+                transposed.data.push(self.data[i * self.col + j]);
+            }
+        }
+        transposed
+    }
+
+    /// Transposes the matrix in place, turning a `row` x `col` matrix into a
+    /// `col` x `row` one. Square matrices are transposed by swapping elements
+    /// across the diagonal with no extra memory. Rectangular matrices follow
+    /// the cycles of the row-major permutation, tracking visited positions in
+    /// a bitset of `row * col` bits instead of copying `data`.
+    pub fn transpose_in_place(&mut self) {
+        let (row, col) = (self.row, self.col);
+        if row == col {
+            for i in 0..row {
+                for j in (i + 1)..col {
+                    self.data.swap(i * col + j, j * col + i);
+                }
+            }
+        } else if row > 1 && col > 1 {
+            let len = row * col;
+            let mut visited = vec![0u64; len.div_ceil(64)];
+            // The first and last elements never move.
+            for start in 1..(len - 1) {
+                if visited[start / 64] & (1 << (start % 64)) != 0 {
+                    continue;
+                }
+                let mut carried = self.data[start];
+                let mut current = start;
+                loop {
+                    let next = (current % col) * row + current / col;
+                    mem::swap(&mut self.data[next], &mut carried);
+                    visited[next / 64] |= 1 << (next % 64);
+                    current = next;
+                    if current == start {
+                        break;
+                    }
+                }
+            }
+        }
+        self.row = col;
+        self.col = row;
+    }
+
+    /// Returns `[self | other]`, the matrix with the columns of `other` to the
+    /// right of those of `self`, or `MatrixError::DimensionMismatch` naming
+    /// both shapes if the row counts differ.
+    pub fn hstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.row != other.row {
+            return Err(MatrixError::DimensionMismatch { left: self.size(), right: other.size(), op: "stack horizontally" });
+        }
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        for (left, right) in self.rows().zip(other.rows()) {
+            data.extend_from_slice(left);
+            data.extend_from_slice(right);
+        }
+        Ok(Matrix{ data, row: self.row, col: self.col + other.col })
+    }
+
+    /// Returns the matrix with the rows of `other` below those of `self`, or
+    /// `MatrixError::DimensionMismatch` naming both shapes if the column
+    /// counts differ.
+    pub fn vstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.col != other.col {
+            return Err(MatrixError::DimensionMismatch { left: self.size(), right: other.size(), op: "stack vertically" });
+        }
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        data.extend_from_slice(&self.data);
+        data.extend_from_slice(&other.data);
+        Ok(Matrix{ data, row: self.row + other.row, col: self.col })
+    }
+
+    /// Returns a copy of the block spanning `rows` and `cols`, so
+    /// `m.submatrix(0..k, 0..k)` is the leading `k x k` block. An empty range
+    /// gives a matrix with no rows or no columns. If either range ends past
+    /// the matrix or starts after it ends, panic.
+    pub fn submatrix(&self, rows: ops::Range<usize>, cols: ops::Range<usize>) -> Matrix<T> {
+        self.check_ranges(&rows, &cols);
+        let mut data = Vec::with_capacity(rows.len() * cols.len());
+        for i in rows.clone() {
+            data.extend_from_slice(&self.data[i * self.col + cols.start..i * self.col + cols.end]);
+        }
+        Matrix{ data, row: rows.len(), col: cols.len() }
+    }
+
+    /// Splits the matrix into copies of its first `i` rows and of the rest,
+    /// the inverse of `vstack`. Splitting at `0` or at `row` gives an empty
+    /// `0 x col` matrix and a full copy. If `i > row`, panic.
+    pub fn split_at_row(&self, i: usize) -> (Matrix<T>, Matrix<T>) {
+        if i > self.row {
+            panic!("cannot split {}x{} matrix at row {}: index out of bounds", self.row, self.col, i);
+        }
+        let (top, bottom) = self.data.split_at(i * self.col);
+        (Matrix{ data: top.to_vec(), row: i, col: self.col },
+         Matrix{ data: bottom.to_vec(), row: self.row - i, col: self.col })
+    }
+
+    /// Splits the matrix into copies of its first `j` columns and of the
+    /// rest, the inverse of `hstack`. Splitting at `0` or at `col` gives an
+    /// empty `row x 0` matrix and a full copy. If `j > col`, panic.
+    pub fn split_at_col(&self, j: usize) -> (Matrix<T>, Matrix<T>) {
+        if j > self.col {
+            panic!("cannot split {}x{} matrix at column {}: index out of bounds", self.row, self.col, j);
+        }
+        let mut left = Vec::with_capacity(self.row * j);
+        let mut right = Vec::with_capacity(self.row * (self.col - j));
+        for values in self.rows() {
+            left.extend_from_slice(&values[..j]);
+            right.extend_from_slice(&values[j..]);
+        }
+        (Matrix{ data: left, row: self.row, col: j },
+         Matrix{ data: right, row: self.row, col: self.col - j })
+    }
+
+    /// Assembles one matrix from a grid of blocks, given as a slice of block
+    /// rows. All blocks in a block row must have the height of its first
+    /// block, and all blocks in a block column the width of the block above
+    /// it in the first block row. Returns `MatrixError::RaggedRows` if the
+    /// block rows do not all have the same number of blocks, or
+    /// `MatrixError::BlockShape` for the first block, in row-major order,
+    /// whose shape does not fit. An empty grid gives a 0x0 matrix.
+    pub fn from_blocks(blocks: &[&[&Matrix<T>]]) -> Result<Matrix<T>, MatrixError> {
+        let widths: Vec<usize> = match blocks.first() {
+            Some(first) => first.iter().map(|block| block.col).collect(),
+            None => return Ok(Matrix{ data: Vec::new(), row: 0, col: 0 }),
+        };
+        let mut heights = Vec::with_capacity(blocks.len());
+        for (bi, block_row) in blocks.iter().enumerate() {
+            if block_row.len() != widths.len() {
+                return Err(MatrixError::RaggedRows { row: bi, expected: widths.len(), actual: block_row.len() });
+            }
+            let height = block_row.first().map_or(0, |block| block.row);
+            for (bj, (block, &width)) in block_row.iter().zip(&widths).enumerate() {
+                if block.size() != (height, width) {
+                    return Err(MatrixError::BlockShape { block: (bi, bj), shape: block.size(), expected: (height, width) });
+                }
+            }
+            heights.push(height);
+        }
+        let row = heights.iter().sum();
+        let col = widths.iter().sum();
+        let mut data = Vec::with_capacity(row * col);
+        for (block_row, &height) in blocks.iter().zip(&heights) {
+            for i in 0..height {
+                for block in block_row.iter() {
+                    data.extend_from_slice(&block.data[i * block.col..(i + 1) * block.col]);
+                }
+            }
+        }
+        Ok(Matrix{ data, row, col })
+    }
+
+    /// Inserts `values` as a new row at position `at`, shifting the rows from
+    /// `at` onward down by one; `at == row` appends. A 0x0 matrix takes its
+    /// column count from `values`. Returns
+    /// `MatrixError::IndexOutOfBounds` if `at > row`, or
+    /// `MatrixError::LengthMismatch` if `values` does not have one element
+    /// per column, leaving `self` unchanged.
+    pub fn insert_row(&mut self, at: usize, values: &[T]) -> Result<(), MatrixError> {
+        if at > self.row {
+            return Err(MatrixError::IndexOutOfBounds { index: at, shape: self.size(), op: "insert a row" });
+        }
+        if self.size() == (0, 0) {
+            self.col = values.len();
+        } else if values.len() != self.col {
+            return Err(MatrixError::LengthMismatch { expected: self.col, actual: values.len() });
+        }
+        let start = at * self.col;
+        self.data.splice(start..start, values.iter().cloned());
+        self.row += 1;
+        Ok(())
+    }
+
+    /// Inserts `values` as a new column at position `at`, shifting the
+    /// columns from `at` onward right by one; `at == col` appends. A 0x0
+    /// matrix takes its row count from `values`. Returns
+    /// `MatrixError::IndexOutOfBounds` if `at > col`, or
+    /// `MatrixError::LengthMismatch` if `values` does not have one element
+    /// per row, leaving `self` unchanged.
+    pub fn insert_col(&mut self, at: usize, values: &[T]) -> Result<(), MatrixError> {
+        if at > self.col {
+            return Err(MatrixError::IndexOutOfBounds { index: at, shape: self.size(), op: "insert a column" });
+        }
+        if self.size() == (0, 0) {
+            self.row = values.len();
+        } else if values.len() != self.row {
+            return Err(MatrixError::LengthMismatch { expected: self.row, actual: values.len() });
+        }
+        let mut data = Vec::with_capacity(self.data.len() + values.len());
+        for (i, &value) in values.iter().enumerate() {
+            let row = &self.data[i * self.col..(i + 1) * self.col];
+            data.extend_from_slice(&row[..at]);
+            data.push(value);
+            data.extend_from_slice(&row[at..]);
+        }
+        self.data = data;
+        self.col += 1;
+        Ok(())
+    }
+
+    /// Appends `values` as a new last row. The first push into a 0x0 matrix
+    /// sets its column count; otherwise `values` must have one element per
+    /// column, or `MatrixError::LengthMismatch` is returned. The
+    /// row is added to the end of the buffer, so building a matrix row by row
+    /// costs amortized O(col) per push, as `Vec::push` does.
+    pub fn push_row(&mut self, values: &[T]) -> Result<(), MatrixError> {
+        self.insert_row(self.row, values)
+    }
+
+    /// Appends `values` as a new last column. The first push into a 0x0
+    /// matrix sets its row count; otherwise `values` must have one element
+    /// per row, or `MatrixError::LengthMismatch` is returned. The
+    /// data is stored in row-major order, so each push lays out the whole
+    /// buffer again in O(row * col); to build a matrix column by column,
+    /// push the columns as rows and `transpose` once at the end.
+    pub fn push_col(&mut self, values: &[T]) -> Result<(), MatrixError> {
+        self.insert_col(self.col, values)
+    }
+
+    /// Multiplies every element of row `i` by `factor` in place. If `i` is
+    /// out of bounds, panic.
+    pub fn scale_row(&mut self, i: usize, factor: T)
+        where T: ops::Mul<Output = T>
+    {
+        self.check_row_index(i);
+        for value in &mut self.data[i * self.col..(i + 1) * self.col] {
+            *value = *value * factor;
+        }
+    }
+
+    /// Adds `factor` times row `src` to row `dst` in place, the elementary
+    /// row operation used in Gaussian elimination. `src` may equal `dst`. If
+    /// either index is out of bounds, panic.
+    pub fn add_scaled_row(&mut self, src: usize, dst: usize, factor: T)
+        where T: ops::Add<Output = T> + ops::Mul<Output = T>
+    {
+        self.check_row_index(src);
+        self.check_row_index(dst);
+        for k in 0..self.col {
+            let value = self.data[src * self.col + k];
+            let target = &mut self.data[dst * self.col + k];
+            *target = *target + factor * value;
+        }
+    }
+
+    /// Multiplies every element of column `j` by `factor` in place. If `j`
+    /// is out of bounds, panic.
+    pub fn scale_col(&mut self, j: usize, factor: T)
+        where T: ops::Mul<Output = T>
+    {
+        self.check_col_index(j);
+        for value in self.data.iter_mut().skip(j).step_by(self.col) {
+            *value = *value * factor;
+        }
+    }
+
+    /// Adds `factor` times column `src` to column `dst` in place. `src` may
+    /// equal `dst`. If either index is out of bounds, panic.
+    pub fn add_scaled_col(&mut self, src: usize, dst: usize, factor: T)
+        where T: ops::Add<Output = T> + ops::Mul<Output = T>
+    {
+        self.check_col_index(src);
+        self.check_col_index(dst);
+        for start in (0..self.data.len()).step_by(self.col) {
+            let value = self.data[start + src];
+            let target = &mut self.data[start + dst];
+            *target = *target + factor * value;
+        }
+    }
+
+    /// Returns `MatrixError::DimensionMismatch` for `op` unless `self` and
+    /// `rhs` have the same number of rows and columns.
+    fn check_same_size<U>(&self, rhs: &Matrix<U>, op: &'static str) -> Result<(), MatrixError> {
+        if self.row != rhs.row || self.col != rhs.col {
+            return Err(MatrixError::DimensionMismatch { left: self.size(), right: (rhs.row, rhs.col), op });
+        }
+        Ok(())
+    }
+
+    /// Returns the mask of `f` applied to corresponding elements, or
+    /// `MatrixError::DimensionMismatch` if the shapes differ.
+    fn compare<F: Fn(&T, &T) -> bool>(&self, rhs: &Matrix<T>, f: F) -> Result<Matrix<bool>, MatrixError> {
+        self.check_same_size(rhs, "compare")?;
+        let data = self.data.iter().zip(rhs.data.iter()).map(|(a, b)| f(a, b)).collect();
+        Ok(Matrix{ data, row: self.row, col: self.col })
+    }
+
+    /// Returns `MatrixError::NotSquare` for `op` unless `self.row == self.col`.
+    fn check_square(&self, op: &'static str) -> Result<(), MatrixError> {
+        if self.row != self.col {
+            return Err(MatrixError::NotSquare { shape: self.size(), op });
+        }
+        Ok(())
+    }
+
+    /// Returns `MatrixError::DimensionMismatch` unless `self.col == rhs.row`.
+    fn check_mul_size<U>(&self, rhs: &Matrix<U>) -> Result<(), MatrixError> {
+        if self.col != rhs.row {
+            return Err(MatrixError::DimensionMismatch { left: self.size(), right: (rhs.row, rhs.col), op: "multiply" });
+        }
+        Ok(())
+    }
+
+    /// Returns the sum of `self` and `rhs`, or `MatrixError::DimensionMismatch`
+    /// if `self.row != rhs.row || self.col != rhs.col`.
+    pub fn checked_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: ops::Add<Output = T>
+    {
+        self.check_same_size(rhs, "add")?;
+        let mut add_matrix = Matrix::new_empty(self.row, self.col);
+        for i in 0..self.data.len() {
+            add_matrix.data.push(self.data[i] + rhs.data[i]);
+        }
+        Ok(add_matrix)
+    }
+
+    /// Returns the subtraction of `rhs` from `self`, or
+    /// `MatrixError::DimensionMismatch` if `self.row != rhs.row || self.col != rhs.col`.
+    pub fn checked_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: ops::Sub<Output = T>
+    {
+        self.check_same_size(rhs, "subtract")?;
+        let mut sub_matrix = Matrix::new_empty(self.row, self.col);
+        for i in 0..self.data.len() {
+            sub_matrix.data.push(self.data[i] - rhs.data[i]);
+        }
+        Ok(sub_matrix)
+    }
+
+    /// Returns the element-wise remainder of `self` divided by `rhs`, or
+    /// `MatrixError::DimensionMismatch` if `self.row != rhs.row || self.col != rhs.col`.
+    pub fn checked_rem(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: ops::Rem<Output = T>
+    {
+        self.check_same_size(rhs, "take the remainder of")?;
+        let data = self.data.iter().zip(rhs.data.iter()).map(|(&a, &b)| a % b).collect();
+        Ok(Matrix{ data, row: self.row, col: self.col })
+    }
+
+    /// Returns the multiplication of `self` by `rhs`, or
+    /// `MatrixError::DimensionMismatch` if `self.col != rhs.row`. Each dot
+    /// product starts from `T::default()`, so an inner dimension of zero
+    /// gives the `self.row x rhs.col` zero matrix.
+    pub fn checked_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: ops::Add<Output = T> + ops::Mul<Output = T> + Default
+    {
+        self.check_mul_size(rhs)?;
+        let mut mul_matrix = Matrix::new_empty(self.row, rhs.col);
+        for row in self.rows() {
+            for j in 0..rhs.col {
+                let sum = row.iter().enumerate().fold(T::default(), |sum, (k, &a)| sum + a * rhs.data[k * rhs.col + j]);
+                mul_matrix.data.push(sum);
+            }
+        }
+        Ok(mul_matrix)
+    }
+
+    /// Returns the matrix-vector product `self * v`, treating `v` as a column
+    /// vector. A matrix without columns gives a zero vector of length
+    /// `self.row`. If `v.len() != self.col`, panic.
+    pub fn mul_vec(&self, v: &[T]) -> Vec<T>
+        where T: ops::Add<Output = T> + ops::Mul<Output = T> + Default
+    {
+        if v.len() != self.col {
+            panic!("cannot multiply {}x{} matrix by vector of length {}", self.row, self.col, v.len());
+        }
+        self.rows().map(|row| {
+            row.iter().zip(v.iter()).fold(T::default(), |sum, (&a, &b)| sum + a * b)
+        }).collect()
+    }
+
+    /// Returns the vector-matrix product `v * self`, treating `v` as a row
+    /// vector. A matrix without rows gives a zero vector of length
+    /// `self.col`. If `v.len() != self.row`, panic.
+    pub fn vec_mul(&self, v: &[T]) -> Vec<T>
+        where T: ops::Add<Output = T> + ops::Mul<Output = T> + Default
+    {
+        if v.len() != self.row {
+            panic!("cannot multiply vector of length {} by {}x{} matrix", v.len(), self.row, self.col);
+        }
+        let mut result = vec![T::default(); self.col];
+        // Walk the matrix row by row so every access is contiguous.
+        for (row, &factor) in self.rows().zip(v.iter()) {
+            for (sum, &a) in result.iter_mut().zip(row.iter()) {
+                *sum = *sum + factor * a;
+            }
+        }
+        result
+    }
+
+    /// Returns a matrix of the same size with every element of `self`
+    /// multiplied by `factor`.
+    pub fn scale(&self, factor: T) -> Matrix<T>
+        where T: ops::Mul<Output = T>
+    {
+        Matrix{ data: self.data.iter().map(|&value| value * factor).collect(), row: self.row, col: self.col }
+    }
+
+    /// Returns the element-wise (Hadamard) product of `self` and `rhs`, or
+    /// `MatrixError::DimensionMismatch` if `self.row != rhs.row || self.col != rhs.col`.
+    pub fn checked_component_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+        where T: ops::Mul<Output = T>
+    {
+        self.check_same_size(rhs, "multiply element-wise")?;
+        let data = self.data.iter().zip(rhs.data.iter()).map(|(&a, &b)| a * b).collect();
+        Ok(Matrix{ data, row: self.row, col: self.col })
+    }
+
+    /// Returns the element-wise (Hadamard) product of `self` and `rhs`. If
+    /// `self.row != rhs.row || self.col != rhs.col`, panic.
+    pub fn component_mul(&self, rhs: &Matrix<T>) -> Matrix<T>
+        where T: ops::Mul<Output = T>
+    {
+        unwrap_op(self.checked_component_mul(rhs))
+    }
+
+    /// Multiplies `self` element-wise by `rhs` in place. If
+    /// `self.row != rhs.row || self.col != rhs.col`, panic.
+    pub fn component_mul_assign(&mut self, rhs: &Matrix<T>)
+        where T: ops::MulAssign
+    {
+        if let Err(err) = self.check_same_size(rhs, "multiply element-wise") {
+            panic!("{}", err);
+        }
+        for (value, &other) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *value *= other;
+        }
+    }
+
+    /// Returns the element-wise quotient of `self` by `rhs`. If
+    /// `self.row != rhs.row || self.col != rhs.col`, panic. If `rhs` holds an
+    /// integer zero, panic with its position; float zeros yield inf or NaN.
+    pub fn component_div(&self, rhs: &Matrix<T>) -> Matrix<T>
+        where T: ops::Div<Output = T> + Divisor
+    {
+        if let Err(err) = self.check_same_size(rhs, "divide element-wise") {
+            panic!("{}", err);
+        }
+        let mut data = Vec::with_capacity(self.data.len());
+        for (index, (&a, &b)) in self.data.iter().zip(rhs.data.iter()).enumerate() {
+            if b.is_zero_divisor() {
+                panic!("attempt to divide by zero at ({}, {})", index / self.col, index % self.col);
+            }
+            data.push(a / b);
+        }
+        Matrix{ data, row: self.row, col: self.col }
+    }
+}
+
+/// Returns the index of the first value that no later value beats, where
+/// `beats(value, best)` decides whether `value` replaces the current best.
+/// Values that are not comparable with themselves (NaN) are skipped.
+fn best_index<'a, T, I, F>(values: I, beats: &F) -> Option<usize>
+    where T: PartialOrd + 'a, I: Iterator<Item = &'a T>, F: Fn(&T, &T) -> bool
+{
+    let mut best: Option<(usize, &T)> = None;
+    for (index, value) in values.enumerate() {
+        if value.partial_cmp(value).is_none() {
+            continue;
+        }
+        match best {
+            Some((_, current)) if !beats(value, current) => {}
+            _ => best = Some((index, value)),
+        }
+    }
+    best.map(|(index, _)| index)
+}
+
+/// Unwraps the result of a checked operation, panicking with the error's message.
+fn unwrap_op<T>(result: Result<Matrix<T>, MatrixError>) -> Matrix<T> {
+    match result {
+        Ok(matrix) => matrix,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Swaps rows `i` and `j` in place. Swapping a row with itself does
+    /// nothing. If either index is out of bounds, panic.
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        self.check_row_index(i);
+        self.check_row_index(j);
+        self.swap_rows_raw(i, j);
+    }
+
+    /// Swaps columns `i` and `j` in place, one pair of elements per row.
+    /// Swapping a column with itself does nothing. If either index is out of
+    /// bounds, panic.
+    pub fn swap_cols(&mut self, i: usize, j: usize) {
+        self.check_col_index(i);
+        self.check_col_index(j);
+        if i != j {
+            for start in (0..self.data.len()).step_by(self.col) {
+                self.data.swap(start + i, start + j);
+            }
+        }
+    }
+
+    /// Removes row `i`, shifting the rows below it up by one, and returns its
+    /// elements. Removing the only row leaves a `0 x col` matrix. If `i` is
+    /// out of bounds, panic.
+    pub fn remove_row(&mut self, i: usize) -> Vec<T> {
+        self.check_row_index(i);
+        let removed = self.data.drain(i * self.col..(i + 1) * self.col).collect();
+        self.row -= 1;
+        removed
+    }
+
+    /// Removes column `j`, shifting the columns right of it left by one, and
+    /// returns its elements from top to bottom. The buffer is rebuilt in a
+    /// single pass. Removing the only column leaves a `row x 0` matrix. If
+    /// `j` is out of bounds, panic.
+    pub fn remove_col(&mut self, j: usize) -> Vec<T> {
+        self.check_col_index(j);
+        let mut removed = Vec::with_capacity(self.row);
+        let mut kept = Vec::with_capacity(self.data.len() - self.row);
+        for (index, value) in mem::take(&mut self.data).into_iter().enumerate() {
+            if index % self.col == j {
+                removed.push(value);
+            } else {
+                kept.push(value);
+            }
+        }
+        self.data = kept;
+        self.col -= 1;
+        removed
+    }
+
+    /// Panics unless `rows` and `cols` are ranges within the matrix that do
+    /// not start after they end.
+    fn check_ranges(&self, rows: &ops::Range<usize>, cols: &ops::Range<usize>) {
+        if rows.start > rows.end || rows.end > self.row {
+            panic!("row range {:?} out of bounds for {}x{} matrix", rows, self.row, self.col);
+        }
+        if cols.start > cols.end || cols.end > self.col {
+            panic!("column range {:?} out of bounds for {}x{} matrix", cols, self.row, self.col);
+        }
+    }
+
+    /// Panics unless `index` is a valid row index.
+    fn check_row_index(&self, index: usize) {
+        if index >= self.row {
+            panic!("row index {} out of bounds for {}x{} matrix", index, self.row, self.col);
+        }
+    }
+
+    /// Panics unless `index` is a valid column index.
+    fn check_col_index(&self, index: usize) {
+        if index >= self.col {
+            panic!("column index {} out of bounds for {}x{} matrix", index, self.row, self.col);
+        }
+    }
+
+    /// Swaps rows `i` and `j` element by element. Both must be in bounds.
+    fn swap_rows_raw(&mut self, i: usize, j: usize) {
+        if i != j {
+            for k in 0..self.col {
+                self.data.swap(i * self.col + k, j * self.col + k);
+            }
+        }
+    }
+}
+
+impl<T: Copy + From<u8>> Matrix<T> {
+    /// Creates a `row` x `col` matrix with every element set to zero.
+    pub fn zeros(row: usize, col: usize) -> Matrix<T> {
+        Matrix::filled(row, col, T::from(0))
+    }
+
+    /// Creates a `row` x `col` matrix with every element set to one.
+    pub fn ones(row: usize, col: usize) -> Matrix<T> {
+        Matrix::filled(row, col, T::from(1))
+    }
+
+    /// Creates the `n` x `n` identity matrix: ones on the main diagonal and
+    /// zeros everywhere else.
+    pub fn identity(n: usize) -> Matrix<T> {
+        let mut identity = Matrix::zeros(n, n);
+        for i in 0..n {
+            identity.data[i * n + i] = T::from(1);
+        }
+        identity
+    }
+
+    /// Creates an `n` x `n` matrix with `values` on the main diagonal and
+    /// zeros everywhere else, where `n == values.len()`.
+    pub fn from_diag(values: &[T]) -> Matrix<T> {
+        let n = values.len();
+        let mut diag = Matrix::zeros(n, n);
+        for (i, &value) in values.iter().enumerate() {
+            diag.data[i * n + i] = value;
+        }
+        diag
+    }
+
+    /// Creates the block diagonal matrix with `blocks` along its diagonal,
+    /// from top left to bottom right, and zeros everywhere else. Blocks may
+    /// be rectangular: the row count of the result is the sum of the blocks'
+    /// row counts, and likewise for columns. An empty slice gives a 0x0
+    /// matrix.
+    pub fn block_diag(blocks: &[&Matrix<T>]) -> Matrix<T> {
+        let row = blocks.iter().map(|block| block.row).sum();
+        let col = blocks.iter().map(|block| block.col).sum();
+        let mut result = Matrix::zeros(row, col);
+        let (mut top, mut left) = (0, 0);
+        for block in blocks {
+            for (i, values) in block.rows().enumerate() {
+                let start = (top + i) * col + left;
+                result.data[start..start + block.col].copy_from_slice(values);
+            }
+            top += block.row;
+            left += block.col;
+        }
+        result
+    }
+
+    /// Returns `true` if `self` is square and every element off the main
+    /// diagonal is zero.
+    pub fn is_diagonal(&self) -> bool
+        where T: PartialEq
+    {
+        let zero = T::from(0);
+        self.is_square() && self.indexed_iter().all(|(i, j, &value)| i == j || value == zero)
+    }
+
+    /// Returns `true` if `self` is square with ones on the main diagonal and
+    /// zeros everywhere else.
+    pub fn is_identity(&self) -> bool
+        where T: PartialEq
+    {
+        let (zero, one) = (T::from(0), T::from(1));
+        self.is_square() && self.indexed_iter().all(|(i, j, &value)| value == if i == j { one } else { zero })
+    }
+
+    /// Returns `true` if every element below the main diagonal, i.e. at
+    /// `(i, j)` with `i > j`, is zero. Rectangular matrices are allowed.
+    pub fn is_upper_triangular(&self) -> bool
+        where T: PartialEq
+    {
+        let zero = T::from(0);
+        self.indexed_iter().all(|(i, j, &value)| i <= j || value == zero)
+    }
+
+    /// Returns `true` if every element above the main diagonal, i.e. at
+    /// `(i, j)` with `i < j`, is zero. Rectangular matrices are allowed.
+    pub fn is_lower_triangular(&self) -> bool
+        where T: PartialEq
+    {
+        let zero = T::from(0);
+        self.indexed_iter().all(|(i, j, &value)| i >= j || value == zero)
+    }
+
+    /// Returns a copy of `self` with every element below the `k`-th diagonal
+    /// set to zero, keeping `(i, j)` where `j - i >= k`. `k = 0` is the main
+    /// diagonal, positive `k` lies above it and negative `k` below it, as in
+    /// NumPy's `triu`.
+    pub fn triu(&self, k: isize) -> Matrix<T> {
+        let zero = T::from(0);
+        Matrix::from_fn(self.row, self.col, |i, j| {
+            if j as isize - i as isize >= k { self.data[i * self.col + j] } else { zero }
+        })
+    }
+
+    /// Returns a copy of `self` with every element above the `k`-th diagonal
+    /// set to zero, keeping `(i, j)` where `j - i <= k`, as in NumPy's `tril`.
+    pub fn tril(&self, k: isize) -> Matrix<T> {
+        let zero = T::from(0);
+        Matrix::from_fn(self.row, self.col, |i, j| {
+            if j as isize - i as isize <= k { self.data[i * self.col + j] } else { zero }
+        })
+    }
+
+    /// Returns the sum of all elements, or zero for an empty matrix.
+    pub fn sum(&self) -> T
+        where T: ops::Add<Output = T>
+    {
+        self.data.iter().fold(T::from(0), |sum, &value| sum + value)
     }
 
-    /// Creates a new, empty matrix of `row` rows and `col` columns.
-    /// `data` contains no element.
-    pub fn new_empty(row: usize, col: usize) -> Matrix<T> {
-        Matrix{ data: Vec::new(), row: row, col: col }
+    /// Returns the sum of each row, one value per row.
+    pub fn row_sums(&self) -> Vec<T>
+        where T: ops::Add<Output = T>
+    {
+        self.rows().map(|row| row.iter().fold(T::from(0), |sum, &value| sum + value)).collect()
     }
 
-    /// Returns a shared reference to `data`
-    pub fn data(&self) -> &Vec<T> {
-        &self.data
+    /// Returns the sum of each column, one value per column. The data is
+    /// walked once in row-major order, adding each row into the totals.
+    pub fn col_sums(&self) -> Vec<T>
+        where T: ops::Add<Output = T>
+    {
+        let mut sums = vec![T::from(0); self.col];
+        for row in self.rows() {
+            for (sum, &value) in sums.iter_mut().zip(row) {
+                *sum = *sum + value;
+            }
+        }
+        sums
     }
 
-    /// Returns a mutable reference to `data`
-    pub fn mut_data(&mut self) -> &mut Vec<T> {
-        &mut self.data
+    /// Returns the product of all elements, or one for an empty matrix.
+    pub fn product(&self) -> T
+        where T: ops::Mul<Output = T>
+    {
+        self.data.iter().fold(T::from(1), |product, &value| product * value)
     }
 
-    /// Returns the number of rows and columns in the first and second
-    /// elements of the tuple, respectively.
-    pub fn size(&self) -> (usize, usize) {
-        (self.row, self.col)
+    /// Returns the number of elements that are not zero. A float NaN is not
+    /// equal to zero, so it counts, while `-0.0` does not.
+    pub fn count_nonzero(&self) -> usize
+        where T: PartialEq
+    {
+        let zero = T::from(0);
+        self.count_where(|&value| value != zero)
+    }
+
+    /// Returns `self` raised to the power `exp` using binary exponentiation,
+    /// so only O(log exp) multiplications are performed. `self.pow(0)` is the
+    /// identity. If `self` is not square, panic.
+    pub fn pow(&self, exp: u32) -> Matrix<T>
+        where T: ops::Add<Output = T> + ops::Mul<Output = T> + Default
+    {
+        if self.row != self.col {
+            panic!("cannot raise {}x{} matrix to a power: matrix is not square", self.row, self.col);
+        }
+        if exp == 0 {
+            return Matrix::identity(self.row);
+        }
+        let mut base = self.clone();
+        let mut exp = exp;
+        while exp & 1 == 0 {
+            base = &base * &base;
+            exp >>= 1;
+        }
+        // Seed the result with the power of the lowest set bit rather than
+        // multiplying it into the identity.
+        let mut result = base.clone();
+        exp >>= 1;
+        while exp != 0 {
+            base = &base * &base;
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+        }
+        result
     }
 }
 
-impl<'a, T: ops::Add<Output = T> + Copy> ops::Add for &'a Matrix<T> {
+impl<T: ops::Add<Output = T> + Copy> ops::Add for &Matrix<T> {
     type Output = Matrix<T>;
 
     /// Returns the sum of `self` and `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
     fn add(self, rhs: Self) -> Self::Output {
-        if self.row != rhs.row || self.col != rhs.col {
-            panic!();
-        }
-        else {
-            let mut add_matrix = Matrix::new_empty(self.row, self.col);
-            for i in 0..self.data.len() {
-                add_matrix.data.push(self.data[i] + rhs.data[i]);
-            }
-            add_matrix
-        }
+        unwrap_op(self.checked_add(rhs))
     }
 }
 
-impl<'a, T: ops::Add<Output = T> + Copy> ops::Add<Matrix<T>> for &'a Matrix<T> {
+impl<T: ops::Add<Output = T> + Copy> ops::Add<Matrix<T>> for &Matrix<T> {
     type Output = Matrix<T>;
 
     /// Returns the sum of `self` and `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
     fn add(self, rhs: Matrix<T>) -> Self::Output {
-        if self.row != rhs.row || self.col != rhs.col {
-            panic!();
-        } else {
-            let mut add_matrix = Matrix::new_empty(self.row, self.col);
-            for i in 0..self.data.len() {
-                add_matrix.data.push(self.data[i] + rhs.data[i]);
-            }
-            add_matrix
-        }
+        unwrap_op(self.checked_add(&rhs))
     }
 }
 
@@ -80,66 +1443,34 @@ impl<T: ops::Add<Output = T> + Copy> ops::Add for Matrix<T> {
 
     /// Returns the sum of `self` and `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
     fn add(self, rhs: Self) -> Self::Output {
-        if self.row != rhs.row || self.col != rhs.col {
-            panic!();
-        } else {
-            let mut add_matrix = Matrix::new_empty(self.row, self.col);
-            for i in 0..self.data.len() {
-                add_matrix.data.push(self.data[i] + rhs.data[i]);
-            }
-            add_matrix
-        }
+        unwrap_op(self.checked_add(&rhs))
     }
 }
 
-impl<'a, T: ops::Add<Output = T> + Copy> ops::Add<&'a Self> for Matrix<T> {
+impl<T: ops::Add<Output = T> + Copy> ops::Add<&Self> for Matrix<T> {
     type Output = Self;
 
     /// Returns the sum of `self` and `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
     fn add(self, rhs: &Self) -> Self::Output {
-        if self.row != rhs.row || self.col != rhs.col {
-            panic!();
-        } else {
-            let mut add_matrix = Matrix::new_empty(self.row, self.col);
-            for i in 0..self.data.len() {
-                add_matrix.data.push(self.data[i] + rhs.data[i]);
-            }
-            add_matrix
-        }
+        unwrap_op(self.checked_add(rhs))
     }
 }
 
-impl<'a, T: ops::Sub<Output = T> + Copy> ops::Sub for &'a Matrix<T> {
+impl<T: ops::Sub<Output = T> + Copy> ops::Sub for &Matrix<T> {
     type Output = Matrix<T>;
 
     /// Returns the subtraction of `rhs` from `self`. If `self.row != rhs.row || self.col != rhs.col`, panic.
     fn sub(self, rhs: Self) -> Self::Output {
-        if self.row != rhs.row || self.col != rhs.col {
-            panic!();
-        } else {
-            let mut sub_matrix = Matrix::new_empty(self.row, self.col);
-            for i in 0..self.data.len() {
-                sub_matrix.data.push(self.data[i] - rhs.data[i]);
-            }
-            sub_matrix
-        }
+        unwrap_op(self.checked_sub(rhs))
     }
 }
 
-impl<'a, T: ops::Sub<Output = T> + Copy> ops::Sub<Matrix<T>> for &'a Matrix<T> {
+impl<T: ops::Sub<Output = T> + Copy> ops::Sub<Matrix<T>> for &Matrix<T> {
     type Output = Matrix<T>;
 
     /// Returns the subtraction of `rhs` from `self`. If `self.row != rhs.row || self.col != rhs.col`, panic.
     fn sub(self, rhs: Matrix<T>) -> Self::Output {
-        if self.row != rhs.row || self.col != rhs.col {
-            panic!();
-        } else {
-            let mut sub_matrix = Matrix::new_empty(self.row, self.col);
-            for i in 0..self.data.len() {
-                sub_matrix.data.push(self.data[i] - rhs.data[i]);
-            }
-            sub_matrix
-        }
+        unwrap_op(self.checked_sub(&rhs))
     }
 }
 
@@ -148,148 +1479,419 @@ impl<T: ops::Sub<Output = T> + Copy> ops::Sub for Matrix<T> {
 
     /// Returns the subtraction of `rhs` from `self`. If `self.row != rhs.row || self.col != rhs.col`, panic.
     fn sub(self, rhs: Self) -> Self::Output {
-        if self.row != rhs.row || self.col != rhs.col {
-            panic!();
-        } else {
-            let mut sub_matrix = Matrix::new_empty(self.row, self.col);
-            for i in 0..self.data.len() {
-                sub_matrix.data.push(self.data[i] - rhs.data[i]);
-            }
-            sub_matrix
-        }
+        unwrap_op(self.checked_sub(&rhs))
     }
 }
 
-impl<'a, T: ops::Sub<Output = T> + Copy> ops::Sub<&'a Self> for Matrix<T> {
+impl<T: ops::Sub<Output = T> + Copy> ops::Sub<&Self> for Matrix<T> {
     type Output = Self;
 
     /// Returns the subtraction of `rhs` from `self`. If `self.row != rhs.row || self.col != rhs.col`, panic.
     fn sub(self, rhs: &Self) -> Self::Output {
-        if self.row != rhs.row || self.col != rhs.col {
-            panic!();
-        } else {
-            let mut sub_matrix = Matrix::new_empty(self.row, self.col);
-            for i in 0..self.data.len() {
-                sub_matrix.data.push(self.data[i] - rhs.data[i]);
-            }
-            sub_matrix
-        }
+        unwrap_op(self.checked_sub(rhs))
     }
 }
 
-impl<'a, T: ops::Add<Output = T> + ops::Mul<Output = T> + Copy> ops::Mul for &'a Matrix<T> {
+impl<T: ops::Add<Output = T> + ops::Mul<Output = T> + Default + Copy> ops::Mul for &Matrix<T> {
     type Output = Matrix<T>;
 
     /// Returns the multiplication of `self` by `rhs`. If `self.col != rhs.row`, panic.
     fn mul(self, rhs: Self) -> Self::Output {
-        if self.col != rhs.row {
-            panic!();
-        } else {
-            let mut mul_matrix = Matrix::new_empty(self.row, rhs.col);
-            for first_row_index in (0..self.data.len()).step_by(self.col) {
-                for second_col_index in 0..rhs.col {
-                    let mut store_vec: Vec<T> = Vec::new();
-                    let mut second_ele_index = second_col_index;
-                    for first_ele_index in (first_row_index..).take(self.col) {
-                        store_vec.push(self.data[first_ele_index] * rhs.data[second_ele_index]);
-                        second_ele_index += rhs.col;
-                    }
-                    let mut sum = store_vec[0];
-                    for index in 1..store_vec.len() {
-                        sum = sum + store_vec[index];
-                    }
-                    mul_matrix.data.push(sum);
-                }
-            }
-            mul_matrix
-        }
+        unwrap_op(self.checked_mul(rhs))
     }
 }
 
-impl<'a, T: ops::Add<Output = T> + ops::Mul<Output = T> + Copy> ops::Mul<Matrix<T>> for &'a Matrix<T> {
+impl<T: ops::Add<Output = T> + ops::Mul<Output = T> + Default + Copy> ops::Mul<Matrix<T>> for &Matrix<T> {
     type Output = Matrix<T>;
 
     /// Returns the multiplication of `self` by `rhs`. If `self.col != rhs.row`, panic.
     fn mul(self, rhs: Matrix<T>) -> Self::Output {
-        if self.col != rhs.row {
-            panic!();
-        } else {
-            let mut mul_matrix = Matrix::new_empty(self.row, rhs.col);
-            for first_row_index in (0..self.data.len()).step_by(self.col) {
-                for second_col_index in 0..rhs.col {
-                    let mut store_vec: Vec<T> = Vec::new();
-                    let mut second_ele_index = second_col_index;
-                    for first_ele_index in (first_row_index..).take(self.col) {
-                        store_vec.push(self.data[first_ele_index] * rhs.data[second_ele_index]);
-                        second_ele_index += rhs.col;
-                    }
-                    let mut sum = store_vec[0];
-                    for index in 1..store_vec.len() {
-                        sum = sum + store_vec[index];
-                    }
-                    mul_matrix.data.push(sum);
-                }
-            }
-            mul_matrix
-        }
+        unwrap_op(self.checked_mul(&rhs))
     }
 }
 
-impl<T: ops::Add<Output = T> + ops::Mul<Output = T> + Copy> ops::Mul for Matrix<T> {
+impl<T: ops::Add<Output = T> + ops::Mul<Output = T> + Default + Copy> ops::Mul for Matrix<T> {
     type Output = Self;
 
     /// Returns the multiplication of `self` by `rhs`. If `self.col != rhs.row`, panic.
     fn mul(self, rhs: Self) -> Self::Output {
-        if self.col != rhs.row {
-            panic!();
-        } else {
-            let mut mul_matrix = Matrix::new_empty(self.row, rhs.col);
-            for first_row_index in (0..self.data.len()).step_by(self.col) {
-                for second_col_index in 0..rhs.col {
-                    let mut store_vec: Vec<T> = Vec::new();
-                    let mut second_ele_index = second_col_index;
-                    for first_ele_index in (first_row_index..).take(self.col) {
-                        store_vec.push(self.data[first_ele_index] * rhs.data[second_ele_index]);
-                        second_ele_index += rhs.col;
-                    }
-                    let mut sum = store_vec[0];
-                    for index in 1..store_vec.len() {
-                        sum = sum + store_vec[index];
-                    }
-                    mul_matrix.data.push(sum);
-                }
-            }
-            mul_matrix
-        }
+        unwrap_op(self.checked_mul(&rhs))
     }
 }
 
-impl<'a, T: ops::Add<Output = T> + ops::Mul<Output = T> + Copy> ops::Mul<&'a Self> for Matrix<T> {
+impl<T: ops::Add<Output = T> + ops::Mul<Output = T> + Default + Copy> ops::Mul<&Self> for Matrix<T> {
     type Output = Self;
 
     /// Returns the multiplication of `self` by `rhs`. If `self.col != rhs.row`, panic.
     fn mul(self, rhs: &Self) -> Self::Output {
-        if self.col != rhs.row {
-            panic!();
-        } else {
-            let mut mul_matrix = Matrix::new_empty(self.row, rhs.col);
-            for first_row_index in (0..self.data.len()).step_by(self.col) {
-                for second_col_index in 0..rhs.col {
-                    let mut store_vec: Vec<T> = Vec::new();
-                    let mut second_ele_index = second_col_index;
-                    for first_ele_index in (first_row_index..).take(self.col) {
-                        store_vec.push(self.data[first_ele_index] * rhs.data[second_ele_index]);
-                        second_ele_index += rhs.col;
-                    }
-                    let mut sum = store_vec[0];
-                    for index in 1..store_vec.len() {
-                        sum = sum + store_vec[index];
-                    }
-                    mul_matrix.data.push(sum);
-                }
+        unwrap_op(self.checked_mul(rhs))
+    }
+}
+
+impl<T: ops::Add<Output = T> + Copy> ops::Add<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Returns `self` with the scalar `rhs` added to every element.
+    fn add(self, rhs: T) -> Self::Output {
+        Matrix{ data: self.data.iter().map(|&value| value + rhs).collect(), row: self.row, col: self.col }
+    }
+}
+
+impl<T: ops::Add<Output = T> + Copy> ops::Add<T> for Matrix<T> {
+    type Output = Self;
+
+    /// Returns `self` with the scalar `rhs` added to every element.
+    fn add(mut self, rhs: T) -> Self::Output {
+        for value in self.data.iter_mut() {
+            *value = *value + rhs;
+        }
+        self
+    }
+}
+
+impl<T: ops::Sub<Output = T> + Copy> ops::Sub<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Returns `self` with the scalar `rhs` subtracted from every element.
+    fn sub(self, rhs: T) -> Self::Output {
+        Matrix{ data: self.data.iter().map(|&value| value - rhs).collect(), row: self.row, col: self.col }
+    }
+}
+
+impl<T: ops::Sub<Output = T> + Copy> ops::Sub<T> for Matrix<T> {
+    type Output = Self;
+
+    /// Returns `self` with the scalar `rhs` subtracted from every element.
+    fn sub(mut self, rhs: T) -> Self::Output {
+        for value in self.data.iter_mut() {
+            *value = *value - rhs;
+        }
+        self
+    }
+}
+
+impl<T: ops::Mul<Output = T> + Copy> ops::Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Returns `self` with every element multiplied by the scalar `rhs`.
+    fn mul(self, rhs: T) -> Self::Output {
+        self.scale(rhs)
+    }
+}
+
+impl<T: ops::Mul<Output = T> + Copy> ops::Mul<T> for Matrix<T> {
+    type Output = Self;
+
+    /// Returns `self` with every element multiplied by the scalar `rhs`.
+    fn mul(mut self, rhs: T) -> Self::Output {
+        for value in self.data.iter_mut() {
+            *value = *value * rhs;
+        }
+        self
+    }
+}
+
+impl<T: ops::Div<Output = T> + Divisor> ops::Div<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Returns `self` with every element divided by the scalar `rhs`. If `rhs`
+    /// is an integer zero, panic; float division by zero yields inf or NaN.
+    fn div(self, rhs: T) -> Self::Output {
+        if rhs.is_zero_divisor() {
+            panic!("attempt to divide matrix by zero");
+        }
+        Matrix{ data: self.data.iter().map(|&value| value / rhs).collect(), row: self.row, col: self.col }
+    }
+}
+
+impl<T: ops::Div<Output = T> + Divisor> ops::Div<T> for Matrix<T> {
+    type Output = Self;
+
+    /// Returns `self` with every element divided by the scalar `rhs`. If `rhs`
+    /// is an integer zero, panic; float division by zero yields inf or NaN.
+    fn div(mut self, rhs: T) -> Self::Output {
+        if rhs.is_zero_divisor() {
+            panic!("attempt to divide matrix by zero");
+        }
+        for value in self.data.iter_mut() {
+            *value = *value / rhs;
+        }
+        self
+    }
+}
+
+impl<T: ops::DivAssign + Divisor> ops::DivAssign<T> for Matrix<T> {
+    /// Divides every element of `self` by the scalar `rhs` in place. If `rhs`
+    /// is an integer zero, panic; float division by zero yields inf or NaN.
+    fn div_assign(&mut self, rhs: T) {
+        if rhs.is_zero_divisor() {
+            panic!("attempt to divide matrix by zero");
+        }
+        for value in self.data.iter_mut() {
+            *value /= rhs;
+        }
+    }
+}
+
+impl<T: ops::Rem<Output = T> + Copy> ops::Rem for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Returns the element-wise remainder of `self` divided by `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
+    fn rem(self, rhs: Self) -> Self::Output {
+        unwrap_op(self.checked_rem(rhs))
+    }
+}
+
+impl<T: ops::Rem<Output = T> + Copy> ops::Rem<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Returns the element-wise remainder of `self` divided by `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
+    fn rem(self, rhs: Matrix<T>) -> Self::Output {
+        unwrap_op(self.checked_rem(&rhs))
+    }
+}
+
+impl<T: ops::Rem<Output = T> + Copy> ops::Rem for Matrix<T> {
+    type Output = Self;
+
+    /// Returns the element-wise remainder of `self` divided by `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
+    fn rem(self, rhs: Self) -> Self::Output {
+        unwrap_op(self.checked_rem(&rhs))
+    }
+}
+
+impl<T: ops::Rem<Output = T> + Copy> ops::Rem<&Self> for Matrix<T> {
+    type Output = Self;
+
+    /// Returns the element-wise remainder of `self` divided by `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
+    fn rem(self, rhs: &Self) -> Self::Output {
+        unwrap_op(self.checked_rem(rhs))
+    }
+}
+
+impl<T: ops::Rem<Output = T> + Copy> ops::Rem<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Returns `self` with every element replaced by its remainder modulo the scalar `rhs`.
+    fn rem(self, rhs: T) -> Self::Output {
+        Matrix{ data: self.data.iter().map(|&value| value % rhs).collect(), row: self.row, col: self.col }
+    }
+}
+
+impl<T: ops::Rem<Output = T> + Copy> ops::Rem<T> for Matrix<T> {
+    type Output = Self;
+
+    /// Returns `self` with every element replaced by its remainder modulo the scalar `rhs`.
+    fn rem(mut self, rhs: T) -> Self::Output {
+        for value in self.data.iter_mut() {
+            *value = *value % rhs;
+        }
+        self
+    }
+}
+
+impl<T: ops::Neg<Output = T> + Copy> ops::Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Returns a matrix of the same size with every element of `self` negated.
+    fn neg(self) -> Self::Output {
+        Matrix{ data: self.data.iter().map(|&value| -value).collect(), row: self.row, col: self.col }
+    }
+}
+
+impl<T: ops::Neg<Output = T> + Copy> ops::Neg for Matrix<T> {
+    type Output = Self;
+
+    /// Returns a matrix of the same size with every element of `self` negated.
+    fn neg(mut self) -> Self::Output {
+        for value in self.data.iter_mut() {
+            *value = -*value;
+        }
+        self
+    }
+}
+
+impl<T: ops::Not<Output = T> + Copy> ops::Not for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Returns a matrix of the same size with every element of `self`
+    /// inverted: logical negation for `bool`, bitwise complement for integers.
+    fn not(self) -> Self::Output {
+        self.map(|&value| !value)
+    }
+}
+
+impl<T: ops::Not<Output = T> + Copy> ops::Not for Matrix<T> {
+    type Output = Self;
+
+    /// Returns a matrix of the same size with every element of `self`
+    /// inverted: logical negation for `bool`, bitwise complement for integers.
+    fn not(mut self) -> Self::Output {
+        for value in self.data.iter_mut() {
+            *value = !*value;
+        }
+        self
+    }
+}
+
+/// Applies the bitwise operator `f`, named `op` in the panic message, to
+/// corresponding elements of `lhs` and `rhs`. If the shapes differ, panic.
+fn bitwise<T: Copy, F: Fn(T, T) -> T>(lhs: &Matrix<T>, rhs: &Matrix<T>, op: &'static str, f: F) -> Matrix<T> {
+    if let Err(err) = lhs.check_same_size(rhs, op) {
+        panic!("{}", err);
+    }
+    let data = lhs.data.iter().zip(rhs.data.iter()).map(|(&a, &b)| f(a, b)).collect();
+    Matrix{ data, row: lhs.row, col: lhs.col }
+}
+
+/// Implements a bitwise operator element-wise for every combination of owned
+/// and borrowed operands. For `Matrix<bool>` these are the logical operators.
+macro_rules! impl_bitwise_op {
+    ($($trait:ident $method:ident $op:expr;)*) => ($(
+        impl<T: ops::$trait<Output = T> + Copy> ops::$trait for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            /// Applies the operator to corresponding elements of `self` and `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
+            fn $method(self, rhs: Self) -> Self::Output {
+                bitwise(self, rhs, $op, ops::$trait::$method)
+            }
+        }
+
+        impl<T: ops::$trait<Output = T> + Copy> ops::$trait<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            /// Applies the operator to corresponding elements of `self` and `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
+            fn $method(self, rhs: Matrix<T>) -> Self::Output {
+                bitwise(self, &rhs, $op, ops::$trait::$method)
+            }
+        }
+
+        impl<T: ops::$trait<Output = T> + Copy> ops::$trait for Matrix<T> {
+            type Output = Self;
+
+            /// Applies the operator to corresponding elements of `self` and `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
+            fn $method(self, rhs: Self) -> Self::Output {
+                bitwise(&self, &rhs, $op, ops::$trait::$method)
+            }
+        }
+
+        impl<T: ops::$trait<Output = T> + Copy> ops::$trait<&Self> for Matrix<T> {
+            type Output = Self;
+
+            /// Applies the operator to corresponding elements of `self` and `rhs`. If `self.row != rhs.row || self.col != rhs.col`, panic.
+            fn $method(self, rhs: &Self) -> Self::Output {
+                bitwise(&self, rhs, $op, ops::$trait::$method)
             }
-            mul_matrix
         }
+    )*)
+}
+
+impl_bitwise_op! {
+    BitAnd bitand "AND";
+    BitOr bitor "OR";
+    BitXor bitxor "XOR";
+}
+
+impl<T: ops::AddAssign + Copy> ops::AddAssign<&Matrix<T>> for Matrix<T> {
+    /// Adds `rhs` to `self` in place. If `self.row != rhs.row || self.col != rhs.col`, panic.
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        if let Err(err) = self.check_same_size(rhs, "add") {
+            panic!("{}", err);
+        }
+        for (value, &other) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *value += other;
+        }
+    }
+}
+
+impl<T: ops::AddAssign + Copy> ops::AddAssign for Matrix<T> {
+    /// Adds `rhs` to `self` in place. If `self.row != rhs.row || self.col != rhs.col`, panic.
+    fn add_assign(&mut self, rhs: Matrix<T>) {
+        *self += &rhs;
+    }
+}
+
+impl<T: ops::SubAssign + Copy> ops::SubAssign<&Matrix<T>> for Matrix<T> {
+    /// Subtracts `rhs` from `self` in place. If `self.row != rhs.row || self.col != rhs.col`, panic.
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        if let Err(err) = self.check_same_size(rhs, "subtract") {
+            panic!("{}", err);
+        }
+        for (value, &other) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *value -= other;
+        }
+    }
+}
+
+impl<T: ops::SubAssign + Copy> ops::SubAssign for Matrix<T> {
+    /// Subtracts `rhs` from `self` in place. If `self.row != rhs.row || self.col != rhs.col`, panic.
+    fn sub_assign(&mut self, rhs: Matrix<T>) {
+        *self -= &rhs;
+    }
+}
+
+impl<T: ops::Add<Output = T> + Copy> Sum for Matrix<T> {
+    /// Adds up the matrices of `iter`, which must all have the same shape.
+    /// The shape of an empty sum is unknown, so an empty `iter` panics;
+    /// seed a `fold` with `Matrix::zeros` instead when `iter` may be empty.
+    fn sum<I: Iterator<Item = Matrix<T>>>(mut iter: I) -> Matrix<T> {
+        let first = iter.next().expect("cannot sum an empty iterator of matrices");
+        iter.fold(first, |sum, matrix| sum + &matrix)
+    }
+}
+
+impl<'a, T: ops::Add<Output = T> + Copy> Sum<&'a Matrix<T>> for Matrix<T> {
+    /// Adds up the matrices of `iter` as the owned `Sum` does, panicking if
+    /// `iter` is empty.
+    fn sum<I: Iterator<Item = &'a Matrix<T>>>(mut iter: I) -> Matrix<T> {
+        let first = iter.next().cloned().expect("cannot sum an empty iterator of matrices");
+        iter.fold(first, |sum, matrix| sum + matrix)
+    }
+}
+
+impl<T> ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// Returns a shared reference to the element at `(row, col)`. If the index is out of bounds, panic.
+    fn index(&self, index: (usize, usize)) -> &T {
+        let (row, col) = index;
+        if row >= self.row || col >= self.col {
+            panic!("index {:?} out of bounds for {}x{} matrix", index, self.row, self.col);
+        }
+        &self.data[row * self.col + col]
+    }
+}
+
+impl<T> ops::IndexMut<(usize, usize)> for Matrix<T> {
+    /// Returns a mutable reference to the element at `(row, col)`. If the index is out of bounds, panic.
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        let (row, col) = index;
+        if row >= self.row || col >= self.col {
+            panic!("index {:?} out of bounds for {}x{} matrix", index, self.row, self.col);
+        }
+        &mut self.data[row * self.col + col]
+    }
+}
+
+impl<T> ops::Index<usize> for Matrix<T> {
+    type Output = [T];
+
+    /// Returns row `row` as a slice. If `row` is out of bounds, panic.
+    fn index(&self, row: usize) -> &[T] {
+        if row >= self.row {
+            panic!("row index {} out of bounds for {}x{} matrix", row, self.row, self.col);
+        }
+        &self.data[row * self.col..(row + 1) * self.col]
+    }
+}
+
+impl<T> ops::IndexMut<usize> for Matrix<T> {
+    /// Returns row `row` as a mutable slice. If `row` is out of bounds, panic.
+    fn index_mut(&mut self, row: usize) -> &mut [T] {
+        if row >= self.row {
+            panic!("row index {} out of bounds for {}x{} matrix", row, self.row, self.col);
+        }
+        &mut self.data[row * self.col..(row + 1) * self.col]
     }
 }
 
@@ -297,11 +1899,12 @@ impl<T: fmt::Display> fmt::Display for Matrix<T> {
     /// Formats the matrix as follows:
     /// * Writes each row on a separate line. No empty lines before or after any row.
     /// * On each row, writes each element followed by a single space, except no space following the last element of the row.
+    ///
     /// Outputs using `write!(f, ...)`.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut outstr = "".to_owned();
         for index in 0..self.data.len() {
-            if index % &self.col == &self.col - 1 {
+            if index % self.col == self.col - 1 {
                 outstr = outstr + &format!("{}", self.data[index]) + "\n";
             } else {
                 outstr = outstr + &format!("{}", self.data[index]) + " ";
@@ -311,16 +1914,34 @@ impl<T: fmt::Display> fmt::Display for Matrix<T> {
     }
 }
 
-fn main() {
-    //test();
+impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = MatrixError;
+
+    /// Flattens `rows` into a matrix with one row per inner vector. An empty
+    /// outer vector yields a 0x0 matrix. Returns `MatrixError::RaggedRows` if
+    /// the inner vectors do not all have the same length.
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Matrix<T>, MatrixError> {
+        let row = rows.len();
+        let col = rows.first().map_or(0, |first| first.len());
+        let mut data = Vec::with_capacity(row * col);
+        for (i, values) in rows.into_iter().enumerate() {
+            if values.len() != col {
+                return Err(MatrixError::RaggedRows { row: i, expected: col, actual: values.len() });
+            }
+            data.extend(values);
+        }
+        Ok(Matrix{ data, row, col })
+    }
 }
 
-fn test() {
-    let x = Matrix::new(2, 3, &[-2, -1, 0, 1, 2, 3]);
-    let y = Matrix::new(3, 2, &[1, 2, 3, 4, 5, 6]);
-//    assert_eq!(&x + &y - &y, x);
-    let z = &x * &y;
-//    println!("{:?}", z);
-    assert_eq!(format!("{}", x), "-2 -1 0\n1 2 3\n");
-    println!("{:?}\n{:?}", format!("{}", x), format!("{}", z));
-}
\ No newline at end of file
+impl<T> From<Matrix<T>> for Vec<Vec<T>> {
+    /// Splits the matrix into one vector per row.
+    fn from(matrix: Matrix<T>) -> Vec<Vec<T>> {
+        let mut rows = Vec::with_capacity(matrix.row);
+        let mut data = matrix.data.into_iter();
+        for _ in 0..matrix.row {
+            rows.push(data.by_ref().take(matrix.col).collect());
+        }
+        rows
+    }
+}
@@ -0,0 +1,80 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use rand;
+use rand::distr::uniform::SampleUniform;
+
+use Matrix;
+
+/// Returns a pair of independent standard normal samples drawn from `rng`
+/// with the Box–Muller transform.
+fn normal_pair<R: rand::Rng + ?Sized>(rng: &mut R) -> (f64, f64) {
+    // `1 - u` lies in (0, 1], keeping the logarithm finite.
+    let radius = (-2.0 * (1.0 - rng.random::<f64>()).ln()).sqrt();
+    let angle = 2.0 * ::std::f64::consts::PI * rng.random::<f64>();
+    (radius * angle.cos(), radius * angle.sin())
+}
+
+impl<T: SampleUniform + PartialOrd + Copy> Matrix<T> {
+    /// Creates a `row` x `col` matrix of values drawn uniformly from `range`,
+    /// using the thread-local generator `rand::rng()`. If `range` is empty,
+    /// panic.
+    pub fn random(row: usize, col: usize, range: Range<T>) -> Matrix<T> {
+        Matrix::random_with(&mut rand::rng(), row, col, range)
+    }
+
+    /// Creates a `row` x `col` matrix of values drawn uniformly from `range`
+    /// using `rng`, so a seeded generator reproduces the same matrix. If
+    /// `range` is empty, panic.
+    pub fn random_with<R: rand::Rng + ?Sized>(rng: &mut R, row: usize, col: usize, range: Range<T>) -> Matrix<T> {
+        if range.start.partial_cmp(&range.end) != Some(Ordering::Less) {
+            panic!("cannot sample from an empty range");
+        }
+        let data = (0..row * col).map(|_| rng.random_range(range.start..range.end)).collect();
+        Matrix{ data, row, col }
+    }
+}
+
+/// Element types that can hold samples from a normal distribution.
+pub trait SampleNormal: Copy {
+    /// Maps the standard normal sample `z` to the distribution with the given
+    /// `mean` and `std_dev`.
+    fn from_standard(z: f64, mean: Self, std_dev: Self) -> Self;
+}
+
+macro_rules! impl_sample_normal {
+    ($($t:ty)*) => ($(
+        impl SampleNormal for $t {
+            fn from_standard(z: f64, mean: $t, std_dev: $t) -> $t {
+                mean + std_dev * z as $t
+            }
+        }
+    )*)
+}
+
+impl_sample_normal! { f32 f64 }
+
+impl<T: SampleNormal> Matrix<T> {
+    /// Creates a `row` x `col` matrix of values drawn from a normal
+    /// distribution with the given `mean` and `std_dev`, using the
+    /// thread-local generator `rand::rng()`.
+    pub fn random_normal(row: usize, col: usize, mean: T, std_dev: T) -> Matrix<T> {
+        Matrix::random_normal_with(&mut rand::rng(), row, col, mean, std_dev)
+    }
+
+    /// Creates a `row` x `col` matrix of values drawn from a normal
+    /// distribution with the given `mean` and `std_dev` using `rng`, so a
+    /// seeded generator reproduces the same matrix.
+    pub fn random_normal_with<R: rand::Rng + ?Sized>(rng: &mut R, row: usize, col: usize, mean: T, std_dev: T) -> Matrix<T> {
+        let len = row * col;
+        let mut data = Vec::with_capacity(len);
+        while data.len() < len {
+            let (a, b) = normal_pair(rng);
+            data.push(T::from_standard(a, mean, std_dev));
+            if data.len() < len {
+                data.push(T::from_standard(b, mean, std_dev));
+            }
+        }
+        Matrix{ data, row, col }
+    }
+}
@@ -0,0 +1,107 @@
+//! Per-primitive support for scalar operators, such as `2 * &m` and `m / 0`,
+//! and for element-wise functions that only some primitives have, such as `abs`.
+//!
+//! The orphan rules forbid a blanket `impl<T> Mul<Matrix<T>> for T`, so the
+//! left scalar impls are generated per primitive type and forward to the
+//! scalar-on-the-right operators in the crate root.
+
+use std::ops;
+
+use Matrix;
+
+macro_rules! impl_left_scalar_mul {
+    ($($t:ty)*) => ($(
+        impl ops::Mul<Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            /// Returns `rhs` with every element multiplied by `self`.
+            fn mul(self, rhs: Matrix<$t>) -> Matrix<$t> {
+                rhs * self
+            }
+        }
+
+        impl<'a> ops::Mul<&'a Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            /// Returns `rhs` with every element multiplied by `self`.
+            fn mul(self, rhs: &'a Matrix<$t>) -> Matrix<$t> {
+                rhs * self
+            }
+        }
+    )*)
+}
+
+impl_left_scalar_mul! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize f32 f64 }
+
+/// Element types that can tell whether dividing by them is an error.
+///
+/// Integer division by zero has no result, so the division operators check
+/// the divisor up front and panic with a clear message. Float division by
+/// zero is well defined (it yields inf or NaN), so floats never report a
+/// zero divisor.
+pub trait Divisor: Copy {
+    /// Returns `true` if dividing by `self` cannot produce a value.
+    fn is_zero_divisor(self) -> bool;
+}
+
+macro_rules! impl_divisor {
+    ($zero:expr => $($t:ty)*) => ($(
+        impl Divisor for $t {
+            fn is_zero_divisor(self) -> bool {
+                self == $zero
+            }
+        }
+    )*);
+    ($($t:ty)*) => ($(
+        impl Divisor for $t {
+            fn is_zero_divisor(self) -> bool {
+                false
+            }
+        }
+    )*)
+}
+
+impl_divisor! { 0 => i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
+impl_divisor! { f32 f64 }
+
+/// Element types with a sign, for which the absolute value is defined.
+///
+/// The absolute value of the most negative integer does not fit in the type:
+/// `abs` overflows there (panicking in debug builds, wrapping to itself in
+/// release builds) just as the primitive method does, while `checked_abs`
+/// returns `None`. Floats never overflow.
+pub trait Signed: Copy {
+    /// Returns the absolute value of `self`.
+    fn abs(self) -> Self;
+
+    /// Returns the absolute value of `self`, or `None` if it overflows.
+    fn checked_abs(self) -> Option<Self>;
+}
+
+macro_rules! impl_signed {
+    (float => $($t:ty)*) => ($(
+        impl Signed for $t {
+            fn abs(self) -> $t {
+                <$t>::abs(self)
+            }
+
+            fn checked_abs(self) -> Option<$t> {
+                Some(<$t>::abs(self))
+            }
+        }
+    )*);
+    ($($t:ty)*) => ($(
+        impl Signed for $t {
+            fn abs(self) -> $t {
+                <$t>::abs(self)
+            }
+
+            fn checked_abs(self) -> Option<$t> {
+                <$t>::checked_abs(self)
+            }
+        }
+    )*)
+}
+
+impl_signed! { i8 i16 i32 i64 isize }
+impl_signed! { float => f32 f64 }
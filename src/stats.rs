@@ -0,0 +1,234 @@
+//! Descriptive statistics and normalizing transforms over the elements, rows
+//! or columns of float matrices.
+
+use Matrix;
+
+/// Running count, mean and sum of squared deviations, updated with Welford's
+/// method so that no large sums of squares are formed. Adding identical
+/// values leaves the mean exact and the deviation sum at exactly zero.
+#[derive(Clone, Copy)]
+struct Moments {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    fn new() -> Moments {
+        Moments { count: 0, mean: 0.0, m2: 0.0 }
+    }
+
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn of<'a, I: Iterator<Item = &'a f64>>(values: I) -> Moments {
+        let mut moments = Moments::new();
+        for &value in values {
+            moments.push(value);
+        }
+        moments
+    }
+
+    /// The mean, or NaN if no values were pushed.
+    fn mean(&self) -> f64 {
+        if self.count == 0 { f64::NAN } else { self.mean }
+    }
+
+    /// The variance with `count - ddof` degrees of freedom, or NaN if
+    /// `count <= ddof`.
+    fn variance(&self, ddof: usize) -> f64 {
+        if self.count <= ddof { f64::NAN } else { self.m2 / (self.count - ddof) as f64 }
+    }
+}
+
+/// Returns the `q`-th percentile of `values`, interpolating linearly between
+/// the two nearest ranks. Reorders `values`. NaN if `values` is empty or
+/// contains NaN.
+fn percentile_of(values: &mut [f64], q: f64) -> f64 {
+    if values.is_empty() || values.iter().any(|value| value.is_nan()) {
+        return f64::NAN;
+    }
+    let rank = q / 100.0 * (values.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let (_, &mut low, above) = values.select_nth_unstable_by(lower, f64::total_cmp);
+    let fraction = rank - lower as f64;
+    if fraction == 0.0 {
+        return low;
+    }
+    let high = above.iter().cloned().fold(f64::INFINITY, f64::min);
+    low + (high - low) * fraction
+}
+
+impl Matrix<f64> {
+    /// Returns the per-column moments in one row-major pass over the data.
+    fn col_moments(&self) -> Vec<Moments> {
+        let mut moments = vec![Moments::new(); self.col];
+        for row in self.rows() {
+            for (moments, &value) in moments.iter_mut().zip(row) {
+                moments.push(value);
+            }
+        }
+        moments
+    }
+
+    /// Returns the mean of all elements, or NaN for an empty matrix.
+    pub fn mean(&self) -> f64 {
+        Moments::of(self.data.iter()).mean()
+    }
+
+    /// Returns the mean of each row.
+    pub fn row_means(&self) -> Vec<f64> {
+        self.rows().map(|row| Moments::of(row.iter()).mean()).collect()
+    }
+
+    /// Returns the mean of each column.
+    pub fn col_means(&self) -> Vec<f64> {
+        self.col_moments().iter().map(Moments::mean).collect()
+    }
+
+    /// Returns the variance of all elements: the sum of squared deviations
+    /// from the mean divided by `n - ddof`. Pass `ddof = 0` for the population
+    /// variance and `ddof = 1` for the unbiased sample variance. The sum is
+    /// accumulated with Welford's method, so a constant matrix has a variance
+    /// of exactly `0.0`. Returns NaN if `n <= ddof`.
+    pub fn variance(&self, ddof: usize) -> f64 {
+        Moments::of(self.data.iter()).variance(ddof)
+    }
+
+    /// Returns the variance of each row, with `ddof` as in `variance`.
+    pub fn row_variances(&self, ddof: usize) -> Vec<f64> {
+        self.rows().map(|row| Moments::of(row.iter()).variance(ddof)).collect()
+    }
+
+    /// Returns the variance of each column, with `ddof` as in `variance`.
+    pub fn col_variances(&self, ddof: usize) -> Vec<f64> {
+        self.col_moments().iter().map(|moments| moments.variance(ddof)).collect()
+    }
+
+    /// Returns the standard deviation of all elements, the square root of
+    /// `variance(ddof)`.
+    pub fn std_dev(&self, ddof: usize) -> f64 {
+        self.variance(ddof).sqrt()
+    }
+
+    /// Returns the standard deviation of each row, with `ddof` as in `variance`.
+    pub fn row_std_devs(&self, ddof: usize) -> Vec<f64> {
+        self.row_variances(ddof).into_iter().map(f64::sqrt).collect()
+    }
+
+    /// Returns the standard deviation of each column, with `ddof` as in `variance`.
+    pub fn col_std_devs(&self, ddof: usize) -> Vec<f64> {
+        self.col_variances(ddof).into_iter().map(f64::sqrt).collect()
+    }
+
+    /// Returns the median of each column, the 50th percentile as computed
+    /// by `col_percentile`.
+    pub fn col_median(&self) -> Vec<f64> {
+        self.col_percentile(50.0)
+    }
+
+    /// Returns the `q`-th percentile of each column for `q` in `0..=100`,
+    /// interpolating linearly between the two nearest ranks as NumPy's
+    /// default does, so `q = 0` is the minimum and `q = 100` the maximum.
+    /// Each column is copied and partially sorted with
+    /// `select_nth_unstable`. A column containing NaN yields NaN, as does
+    /// every column of a matrix without rows. If `q` is outside `0..=100`
+    /// or NaN, panic.
+    pub fn col_percentile(&self, q: f64) -> Vec<f64> {
+        if !(0.0..=100.0).contains(&q) {
+            panic!("percentile {} is outside 0..=100", q);
+        }
+        let mut column = Vec::with_capacity(self.row);
+        self.columns().map(|values| {
+            column.clear();
+            column.extend(values);
+            percentile_of(&mut column, q)
+        }).collect()
+    }
+
+    /// Counts the elements falling into each of `bins` equal-width bins
+    /// spanning `range = (lo, hi)`. Every bin is half-open, `[left, right)`,
+    /// except the last, which also includes `hi`, as in NumPy. Elements
+    /// outside the range and NaN are dropped rather than clamped into the
+    /// edge bins, so the counts add up to less than the number of elements
+    /// when there are any. If `bins` is zero or the range is not finite with
+    /// `lo < hi`, panic.
+    pub fn histogram(&self, bins: usize, range: (f64, f64)) -> Vec<usize> {
+        let (lo, hi) = range;
+        if bins == 0 {
+            panic!("histogram needs at least one bin");
+        }
+        if !(lo.is_finite() && hi.is_finite() && lo < hi) {
+            panic!("histogram range ({}, {}) is not a finite interval with lo < hi", lo, hi);
+        }
+        let mut counts = vec![0; bins];
+        let width = (hi - lo) / bins as f64;
+        for &value in &self.data {
+            if (lo..=hi).contains(&value) {
+                let bin = ((value - lo) / width) as usize;
+                counts[bin.min(bins - 1)] += 1;
+            }
+        }
+        counts
+    }
+
+    /// Returns a copy with every row divided by its Euclidean norm, so that
+    /// each nonzero row has unit length. Rows of zeros stay zero.
+    pub fn normalize_rows(&self) -> Matrix<f64> {
+        let mut data = self.data.clone();
+        for row in data.chunks_mut(self.col.max(1)) {
+            let norm = row.iter().fold(0.0, |sum, value| sum + value * value).sqrt();
+            if norm > 0.0 {
+                row.iter_mut().for_each(|value| *value /= norm);
+            }
+        }
+        Matrix{ data, row: self.row, col: self.col }
+    }
+
+    /// Returns a copy with every column shifted to mean 0 and scaled to
+    /// (population) standard deviation 1, together with the per-column means
+    /// and scales used, so new data can be transformed the same way with
+    /// `(x - mean) / scale`. A constant column has a scale of `1.0` rather
+    /// than `0.0`, so it becomes all zeros instead of NaN.
+    pub fn standardize_cols(&self) -> (Matrix<f64>, Vec<f64>, Vec<f64>) {
+        let moments = self.col_moments();
+        let means: Vec<f64> = moments.iter().map(Moments::mean).collect();
+        let scales: Vec<f64> = moments.iter()
+            .map(|moments| moments.variance(0).sqrt())
+            .map(|std| if std > 0.0 { std } else { 1.0 })
+            .collect();
+        let standardized = Matrix::from_fn(self.row, self.col, |i, j| {
+            (self.data[i * self.col + j] - means[j]) / scales[j]
+        });
+        (standardized, means, scales)
+    }
+
+    /// Returns the softmax of each row: `exp(x - max) / sum(exp(x - max))`
+    /// with the row maximum subtracted first so large inputs cannot
+    /// overflow. Every row of the result sums to one. A row containing
+    /// `+inf` spreads its mass evenly over those entries, and a row of only
+    /// `-inf` becomes uniform, matching the limit of finite inputs.
+    pub fn softmax_rows(&self) -> Matrix<f64> {
+        let mut data = self.data.clone();
+        for row in data.chunks_mut(self.col.max(1)) {
+            let max = row.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let mut total = 0.0;
+            for value in row.iter_mut() {
+                // `inf - inf` is NaN, so an infinite maximum weighs its own
+                // entries by one and every other entry by zero.
+                *value = if max.is_infinite() {
+                    if *value == max { 1.0 } else { 0.0 }
+                } else {
+                    (*value - max).exp()
+                };
+                total += *value;
+            }
+            row.iter_mut().for_each(|value| *value /= total);
+        }
+        Matrix{ data, row: self.row, col: self.col }
+    }
+}
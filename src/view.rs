@@ -0,0 +1,248 @@
+//! Borrowed rectangular windows into a matrix, created by `Matrix::view`
+//! and `Matrix::view_mut`.
+
+use std::ops;
+
+use {Matrix, MatrixError};
+
+/// A read-only view of a rectangular block of a matrix, created by
+/// `Matrix::view`. The view borrows the matrix's buffer and reads the block
+/// in place, stepping `stride` elements from one row to the next.
+#[derive(Debug, Clone)]
+pub struct MatrixView<'a, T: 'a> {
+    /// The elements from the top-left to the bottom-right of the block, along
+    /// with those of the parent matrix that lie between its rows. Empty, with
+    /// a `stride` of zero, if the block is.
+    data: &'a [T],
+    row: usize,
+    col: usize,
+    stride: usize,
+}
+
+impl<T> Matrix<T> {
+    /// Returns a view of the block spanning `rows` and `cols` without
+    /// copying it. The ranges are checked as in `submatrix`: if either ends
+    /// past the matrix or starts after it ends, panic.
+    pub fn view(&self, rows: ops::Range<usize>, cols: ops::Range<usize>) -> MatrixView<'_, T> {
+        self.check_ranges(&rows, &cols);
+        let (row, col) = (rows.len(), cols.len());
+        if row == 0 || col == 0 {
+            return MatrixView { data: &self.data[..0], row, col, stride: 0 };
+        }
+        let data = &self.data[rows.start * self.col + cols.start..(rows.end - 1) * self.col + cols.end];
+        MatrixView { data, row, col, stride: self.col }
+    }
+
+    /// Returns a mutable view of the block spanning `rows` and `cols`, with
+    /// the ranges checked as in `view`.
+    pub fn view_mut(&mut self, rows: ops::Range<usize>, cols: ops::Range<usize>) -> MatrixViewMut<'_, T> {
+        self.check_ranges(&rows, &cols);
+        let (row, col) = (rows.len(), cols.len());
+        if row == 0 || col == 0 {
+            return MatrixViewMut { data: &mut self.data[..0], row, col, stride: 0 };
+        }
+        let stride = self.col;
+        let data = &mut self.data[rows.start * stride + cols.start..(rows.end - 1) * stride + cols.end];
+        MatrixViewMut { data, row, col, stride }
+    }
+}
+
+/// A mutable view of a rectangular block of a matrix, created by
+/// `Matrix::view_mut`, for updating the block in place. The view holds the
+/// matrix's mutable borrow, so at most one exists at a time and the matrix
+/// cannot be read around it until the view is dropped.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T: 'a> {
+    /// Laid out as in `MatrixView`.
+    data: &'a mut [T],
+    row: usize,
+    col: usize,
+    stride: usize,
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// Returns the number of rows and columns of the view.
+    pub fn size(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// Returns a reference to the element at `row`, `col` of the view, or
+    /// `None` if either index is out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row < self.row && col < self.col {
+            Some(&self.data[row * self.stride + col])
+        } else {
+            None
+        }
+    }
+
+    /// Returns row `i` of the view as a slice. If `i` is out of bounds, panic.
+    pub fn row(&self, i: usize) -> &'a [T] {
+        if i >= self.row {
+            panic!("row index {} out of bounds for {}x{} view", i, self.row, self.col);
+        }
+        &self.data[i * self.stride..i * self.stride + self.col]
+    }
+
+    /// Returns an iterator over the rows of the view as slices, top to bottom.
+    pub fn rows(&self) -> ViewRows<'a, T> {
+        ViewRows { data: self.data, col: self.col, stride: self.stride, front: 0, back: self.row }
+    }
+
+    /// Copies the viewed block into a new matrix, the same one
+    /// `Matrix::submatrix` returns for the same ranges.
+    pub fn to_matrix(&self) -> Matrix<T>
+        where T: Copy
+    {
+        let mut data = Vec::with_capacity(self.row * self.col);
+        for values in self.rows() {
+            data.extend_from_slice(values);
+        }
+        Matrix{ data, row: self.row, col: self.col }
+    }
+}
+
+impl<'a, T> ops::Index<(usize, usize)> for MatrixView<'a, T> {
+    type Output = T;
+
+    /// Returns a shared reference to the element at `(row, col)` of the view. If the index is out of bounds, panic.
+    fn index(&self, index: (usize, usize)) -> &T {
+        match self.get(index.0, index.1) {
+            Some(value) => value,
+            None => panic!("index {:?} out of bounds for {}x{} view", index, self.row, self.col),
+        }
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    /// Returns the number of rows and columns of the view.
+    pub fn size(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// Returns a read-only view of the same block, borrowing this one.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView { data: self.data, row: self.row, col: self.col, stride: self.stride }
+    }
+
+    /// Returns a mutable reference to the element at `row`, `col` of the
+    /// view, or `None` if either index is out of bounds.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.row && col < self.col {
+            Some(&mut self.data[row * self.stride + col])
+        } else {
+            None
+        }
+    }
+
+    /// Returns row `i` of the view as a mutable slice. If `i` is out of
+    /// bounds, panic.
+    pub fn row_mut(&mut self, i: usize) -> &mut [T] {
+        if i >= self.row {
+            panic!("row index {} out of bounds for {}x{} view", i, self.row, self.col);
+        }
+        &mut self.data[i * self.stride..i * self.stride + self.col]
+    }
+
+    /// Applies `f` to every element of the block in place, in row-major
+    /// order. Elements of the matrix outside the block are not touched.
+    pub fn apply<F: FnMut(&mut T)>(&mut self, mut f: F) {
+        for i in 0..self.row {
+            self.row_mut(i).iter_mut().for_each(&mut f);
+        }
+    }
+
+    /// Sets every element of the block to `value`.
+    pub fn fill(&mut self, value: T)
+        where T: Copy
+    {
+        self.apply(|element| *element = value);
+    }
+
+    /// Overwrites the block with the elements of `source`, or returns
+    /// `MatrixError::DimensionMismatch`, leaving the block unchanged, if
+    /// `source` does not have the shape of the view.
+    pub fn copy_from(&mut self, source: &Matrix<T>) -> Result<(), MatrixError>
+        where T: Copy
+    {
+        if source.size() != self.size() {
+            return Err(MatrixError::DimensionMismatch { left: self.size(), right: source.size(), op: "copy into" });
+        }
+        for (i, values) in source.rows().enumerate() {
+            self.row_mut(i).copy_from_slice(values);
+        }
+        Ok(())
+    }
+
+    /// Copies the viewed block into a new matrix.
+    pub fn to_matrix(&self) -> Matrix<T>
+        where T: Copy
+    {
+        self.as_view().to_matrix()
+    }
+}
+
+impl<'a, T> ops::Index<(usize, usize)> for MatrixViewMut<'a, T> {
+    type Output = T;
+
+    /// Returns a shared reference to the element at `(row, col)` of the view. If the index is out of bounds, panic.
+    fn index(&self, index: (usize, usize)) -> &T {
+        let (row, col) = index;
+        if row >= self.row || col >= self.col {
+            panic!("index {:?} out of bounds for {}x{} view", index, self.row, self.col);
+        }
+        &self.data[row * self.stride + col]
+    }
+}
+
+impl<'a, T> ops::IndexMut<(usize, usize)> for MatrixViewMut<'a, T> {
+    /// Returns a mutable reference to the element at `(row, col)` of the view. If the index is out of bounds, panic.
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        let (row, col) = index;
+        if row >= self.row || col >= self.col {
+            panic!("index {:?} out of bounds for {}x{} view", index, self.row, self.col);
+        }
+        &mut self.data[row * self.stride + col]
+    }
+}
+
+/// Iterator over the rows of a view as slices, created by `MatrixView::rows`.
+#[derive(Debug, Clone)]
+pub struct ViewRows<'a, T: 'a> {
+    data: &'a [T],
+    col: usize,
+    stride: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Iterator for ViewRows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.front == self.back {
+            return None;
+        }
+        let start = self.front * self.stride;
+        self.front += 1;
+        Some(&self.data[start..start + self.col])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ViewRows<'a, T> {
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let start = self.back * self.stride;
+        Some(&self.data[start..start + self.col])
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ViewRows<'a, T> {}
@@ -0,0 +1,30 @@
+extern crate your;
+
+use your::Matrix;
+
+#[test]
+fn de_morgan_on_masks() {
+    let a = Matrix::new(2, 2, &[true, true, false, false]);
+    let b = Matrix::new(2, 2, &[true, false, true, false]);
+    assert_eq!(!(&a & &b), !&a | !&b);
+    assert_eq!(!(&a | &b), !&a & !&b);
+    assert_eq!(&a ^ &b, Matrix::new(2, 2, &[false, true, true, false]));
+    assert_eq!(!a, Matrix::new(2, 2, &[false, false, true, true]));
+}
+
+#[test]
+fn xor_round_trip_on_u32() {
+    let data = Matrix::new(2, 3, &[0u32, 1, 0xdead_beef, 42, u32::MAX, 7]);
+    let key = Matrix::new(2, 3, &[0x5555_5555u32, 3, 0xffff_0000, 0, 1, 7]);
+    let encrypted = &data ^ &key;
+    assert_eq!(encrypted[(1, 2)], 0);
+    assert_eq!(encrypted ^ &key, data);
+    assert_eq!(&data & &key, Matrix::new(2, 3, &[0, 1, 0xdead_0000, 0, 1, 7]));
+    assert_eq!(!&key, Matrix::new(2, 3, &[0xaaaa_aaaa, !3, 0x0000_ffff, u32::MAX, !1, !7]));
+}
+
+#[test]
+#[should_panic(expected = "cannot AND 2x2 matrix and 1x4 matrix: dimensions differ")]
+fn bitwise_shape_mismatch_panics() {
+    let _ = Matrix::new(2, 2, &[1, 2, 3, 4]) & Matrix::new(1, 4, &[1, 2, 3, 4]);
+}
@@ -0,0 +1,72 @@
+extern crate your;
+
+use your::{Matrix, MatrixError};
+
+fn count_true(mask: &Matrix<bool>) -> usize {
+    mask.iter().filter(|&&b| b).count()
+}
+
+#[test]
+fn element_wise_comparisons() {
+    let a = Matrix::new(2, 3, &[1, 5, 3, 7, 2, 8]);
+    let b = Matrix::new(2, 3, &[2, 5, 1, 9, 2, 4]);
+    let lt = a.lt(&b).unwrap();
+    assert_eq!(lt, Matrix::new(2, 3, &[true, false, false, true, false, false]));
+    assert_eq!(count_true(&lt), 2);
+    assert_eq!(count_true(&a.le(&b).unwrap()), 4);
+    assert_eq!(count_true(&a.gt(&b).unwrap()), 2);
+    assert_eq!(count_true(&a.ge(&b).unwrap()), 4);
+    assert_eq!(a.eq_elem(&b).unwrap(), Matrix::new(2, 3, &[false, true, false, false, true, false]));
+}
+
+#[test]
+fn comparison_shape_mismatch() {
+    let a = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let b = Matrix::new(1, 4, &[1, 2, 3, 4]);
+    assert_eq!(a.lt(&b), Err(MatrixError::DimensionMismatch { left: (2, 2), right: (1, 4), op: "compare" }));
+    assert!(a.eq_elem(&b).is_err());
+}
+
+#[test]
+fn scalar_comparisons_with_nan() {
+    let m = Matrix::new(2, 2, &[0.5, f64::NAN, 2.0, -1.0]);
+    assert_eq!(m.gt_scalar(0.0), Matrix::new(2, 2, &[true, false, true, false]));
+    assert_eq!(m.le_scalar(0.5), Matrix::new(2, 2, &[true, false, false, true]));
+    assert_eq!(m.lt_scalar(f64::INFINITY), Matrix::new(2, 2, &[true, false, true, true]));
+    assert_eq!(m.ge_scalar(f64::NEG_INFINITY), Matrix::new(2, 2, &[true, false, true, true]));
+    assert_eq!(count_true(&m.eq_scalar(f64::NAN)), 0);
+
+    let nan = Matrix::new(1, 1, &[f64::NAN]);
+    assert_eq!(m.lt(&m).unwrap(), Matrix::new(2, 2, &[false; 4]));
+    assert_eq!(nan.eq_elem(&nan).unwrap(), Matrix::new(1, 1, &[false]));
+}
+
+#[test]
+fn select_implements_relu() {
+    let a = Matrix::new(2, 3, &[-1.5, 0.0, 2.0, 3.5, -0.25, 1.0]);
+    let zeros = Matrix::zeros(2, 3);
+    let relu = Matrix::select(&a.gt_scalar(0.0), &a, &zeros).unwrap();
+    assert_eq!(relu, Matrix::new(2, 3, &[0.0, 0.0, 2.0, 3.5, 0.0, 1.0]));
+}
+
+#[test]
+fn select_winsorizes_with_two_masks() {
+    let a = Matrix::new(1, 5, &[-10, -1, 0, 4, 12]);
+    let lo = Matrix::new(1, 5, &[-2; 5]);
+    let hi = Matrix::new(1, 5, &[5; 5]);
+    let clipped_low = Matrix::select(&a.lt(&lo).unwrap(), &lo, &a).unwrap();
+    let clipped = Matrix::select(&clipped_low.gt(&hi).unwrap(), &hi, &clipped_low).unwrap();
+    assert_eq!(clipped, Matrix::new(1, 5, &[-2, -1, 0, 4, 5]));
+}
+
+#[test]
+fn select_rejects_mismatched_shapes() {
+    let mask = Matrix::new(2, 2, &[true, false, false, true]);
+    let square = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let wide = Matrix::new(1, 4, &[5, 6, 7, 8]);
+    let err = Matrix::select(&mask, &square, &wide).unwrap_err();
+    assert_eq!(err, MatrixError::DimensionMismatch { left: (2, 2), right: (1, 4), op: "select" });
+    assert_eq!(err.to_string(), "cannot select from 1x4 matrix with 2x2 mask: dimensions differ");
+    assert!(Matrix::select(&mask, &wide, &square).is_err());
+    assert_eq!(Matrix::select(&mask, &square, &square).unwrap(), square);
+}
@@ -0,0 +1,145 @@
+extern crate your;
+
+use your::{Matrix, MatrixError};
+
+#[test]
+fn zeros_and_ones_are_fully_populated() {
+    let z: Matrix<i32> = Matrix::zeros(2, 3);
+    assert_eq!(z.size(), (2, 3));
+    assert_eq!(z.data(), &vec![0; 6]);
+    let o: Matrix<f64> = Matrix::ones(3, 2);
+    assert_eq!(o.data(), &vec![1.0; 6]);
+}
+
+#[test]
+fn zeros_is_additive_identity() {
+    let m = Matrix::new(2, 3, &[1, -2, 3, -4, 5, -6]);
+    assert_eq!(Matrix::zeros(2, 3) + &m, m);
+}
+
+#[test]
+fn ones_times_column_sums_rows() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(&m * Matrix::ones(3, 1), Matrix::new(2, 1, &[6, 15]));
+}
+
+#[test]
+fn identity_has_ones_on_diagonal() {
+    let i: Matrix<i32> = Matrix::identity(3);
+    assert_eq!(i, Matrix::new(3, 3, &[1, 0, 0, 0, 1, 0, 0, 0, 1]));
+    let empty: Matrix<i32> = Matrix::identity(0);
+    assert_eq!(empty.size(), (0, 0));
+}
+
+#[test]
+fn identity_is_multiplicative_identity() {
+    let a = Matrix::new(2, 3, &[-2, -1, 0, 1, 2, 3]);
+    let k = a.size().1;
+    assert_eq!(&a * &Matrix::identity(k), a);
+    assert_eq!(&Matrix::identity(a.size().0) * &a, a);
+}
+
+#[test]
+fn filled_repeats_value() {
+    let m = Matrix::filled(2, 3, 7);
+    assert_eq!(m, Matrix::new(2, 3, &[7; 6]));
+    assert_eq!(&m - &m, Matrix::zeros(2, 3));
+}
+
+#[test]
+fn filled_with_zero_extent() {
+    let no_rows = Matrix::filled(0, 3, 1.5);
+    assert_eq!(no_rows.size(), (0, 3));
+    assert!(no_rows.data().is_empty());
+    let no_cols = Matrix::filled(4, 0, 1.5);
+    assert_eq!(no_cols.size(), (4, 0));
+    assert!(no_cols.data().is_empty());
+}
+
+#[test]
+fn from_fn_builds_identity() {
+    let i = Matrix::from_fn(4, 4, |r, c| if r == c { 1 } else { 0 });
+    assert_eq!(i, Matrix::identity(4));
+}
+
+#[test]
+fn from_fn_passes_row_and_column() {
+    let m = Matrix::from_fn(3, 5, |i, j| i * 10 + j);
+    assert_eq!(m.size(), (3, 5));
+    assert_eq!(m[(0, 0)], 0);
+    assert_eq!(m[(0, 4)], 4);
+    assert_eq!(m[(2, 1)], 21);
+    assert_eq!(m[(2, 4)], 24);
+}
+
+#[test]
+fn from_fn_calls_in_row_major_order() {
+    let mut calls = Vec::new();
+    let m = Matrix::from_fn(2, 2, |i, j| {
+        calls.push((i, j));
+        calls.len()
+    });
+    assert_eq!(calls, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    assert_eq!(m, Matrix::new(2, 2, &[1, 2, 3, 4]));
+}
+
+#[test]
+fn from_rows_collects_computed_rows() {
+    let m = Matrix::from_rows((0..3).map(|i| vec![i, i * i])).unwrap();
+    assert_eq!(m, Matrix::new(3, 2, &[0, 0, 1, 1, 2, 4]));
+    let slices: [&[i32]; 2] = [&[1, 2, 3], &[4, 5, 6]];
+    assert_eq!(Matrix::from_rows(slices.iter()).unwrap(), Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]));
+}
+
+#[test]
+fn from_rows_empty_is_zero_by_zero() {
+    let m = Matrix::<f64>::from_rows(Vec::<Vec<f64>>::new()).unwrap();
+    assert_eq!(m.size(), (0, 0));
+}
+
+#[test]
+fn from_rows_reports_offending_row() {
+    let err = Matrix::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6, 7]]).unwrap_err();
+    assert_eq!(err, MatrixError::RaggedRows { row: 2, expected: 2, actual: 3 });
+}
+
+#[test]
+fn from_diag_places_values_on_diagonal() {
+    let d = Matrix::from_diag(&[2, 3, 4]);
+    assert_eq!(d, Matrix::new(3, 3, &[2, 0, 0, 0, 3, 0, 0, 0, 4]));
+    assert_eq!(d.diagonal(), vec![2, 3, 4]);
+    assert_eq!(Matrix::from_diag(&[1, 1]), Matrix::identity(2));
+}
+
+#[test]
+fn from_diag_scales_rows_and_columns() {
+    let x = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    // Left multiplication scales rows, right multiplication scales columns.
+    assert_eq!(Matrix::from_diag(&[10, -1]) * &x, Matrix::new(2, 3, &[10, 20, 30, -4, -5, -6]));
+    assert_eq!(&x * Matrix::from_diag(&[1, 0, 2]), Matrix::new(2, 3, &[1, 0, 6, 4, 0, 12]));
+}
+
+#[test]
+fn try_new_accepts_exact_length() {
+    assert_eq!(Matrix::try_new(2, 3, &[1, 2, 3, 4, 5, 6]), Ok(Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6])));
+    assert_eq!(Matrix::<i32>::try_new(0, 3, &[]).map(|m| m.size()), Ok((0, 3)));
+}
+
+#[test]
+fn try_new_rejects_too_short() {
+    let err = Matrix::try_new(2, 3, &[1, 2]).unwrap_err();
+    assert_eq!(err, MatrixError::LengthMismatch { expected: 6, actual: 2 });
+    assert_eq!(err.to_string(), "expected 6 values, got 2");
+}
+
+#[test]
+fn try_new_rejects_too_long() {
+    assert_eq!(Matrix::try_new(1, 2, &[1, 2, 3]), Err(MatrixError::LengthMismatch { expected: 2, actual: 3 }));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "2x3 matrix needs 6 values")]
+fn new_debug_asserts_length() {
+    Matrix::new(2, 3, &[1, 2]);
+}
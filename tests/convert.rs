@@ -0,0 +1,80 @@
+extern crate your;
+
+use std::convert::TryFrom;
+
+use your::{Matrix, MatrixError};
+
+#[test]
+fn try_from_nested_vectors() {
+    let m = Matrix::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(m, Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]));
+}
+
+#[test]
+fn try_from_empty_is_zero_by_zero() {
+    let m = Matrix::<i32>::try_from(Vec::new()).unwrap();
+    assert_eq!(m.size(), (0, 0));
+}
+
+#[test]
+fn try_from_ragged_rows_errors() {
+    let err = Matrix::try_from(vec![vec![1, 2], vec![3, 4], vec![5]]).unwrap_err();
+    assert_eq!(err, MatrixError::RaggedRows { row: 2, expected: 2, actual: 1 });
+    assert_eq!(err.to_string(), "row 2 has 1 elements, expected 2");
+}
+
+#[test]
+fn nested_vectors_round_trip() {
+    let rows = vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]];
+    let m = Matrix::try_from(rows.clone()).unwrap();
+    assert_eq!(m.size(), (3, 2));
+    assert_eq!(Vec::<Vec<f64>>::from(m), rows);
+}
+
+#[test]
+fn zero_width_rows_round_trip() {
+    let rows: Vec<Vec<i32>> = vec![vec![], vec![]];
+    let m = Matrix::try_from(rows.clone()).unwrap();
+    assert_eq!(m.size(), (2, 0));
+    assert_eq!(Vec::<Vec<i32>>::from(m), rows);
+}
+
+#[test]
+fn cast_i32_to_f64() {
+    let m = Matrix::new(2, 2, &[1i32, -2, 3, i32::MAX]);
+    let floats: Matrix<f64> = m.cast();
+    assert_eq!(floats, Matrix::new(2, 2, &[1.0, -2.0, 3.0, 2147483647.0]));
+    assert_eq!(floats.det().unwrap(), 2147483647.0 + 6.0);
+}
+
+#[test]
+fn cast_u8_to_u32() {
+    let m = Matrix::new(1, 3, &[0u8, 128, 255]);
+    let wide: Matrix<u32> = m.cast();
+    assert_eq!(wide, Matrix::new(1, 3, &[0, 128, 255]));
+    assert_eq!(&wide * 2u32, Matrix::new(1, 3, &[0, 256, 510]));
+}
+
+#[test]
+fn cast_empty_matrix() {
+    let empty = Matrix::<i16>::zeros(0, 3).cast::<i64>();
+    assert_eq!(empty.size(), (0, 3));
+}
+
+#[test]
+fn try_cast_when_every_element_fits() {
+    let m = Matrix::new(2, 2, &[1i64, -2, 300, i64::from(i32::MIN)]);
+    let narrow: Matrix<i32> = m.try_cast().unwrap();
+    assert_eq!(narrow, Matrix::new(2, 2, &[1, -2, 300, i32::MIN]));
+    assert_eq!(Matrix::<i64>::zeros(0, 2).try_cast::<u8>().unwrap().size(), (0, 2));
+}
+
+#[test]
+fn try_cast_reports_first_out_of_range_element() {
+    let m = Matrix::new(2, 3, &[0i64, 12, 255, 7, 256, -1]);
+    let err = m.try_cast::<u8>().unwrap_err();
+    assert_eq!(err, MatrixError::ConversionFailed { at: (1, 1), value: "256".to_string() });
+    assert_eq!(err.to_string(), "cannot convert element 256 at (1, 1) to the target type");
+    let negative = Matrix::new(1, 2, &[3i32, -4]);
+    assert_eq!(negative.try_cast::<u32>(), Err(MatrixError::ConversionFailed { at: (0, 1), value: "-4".to_string() }));
+}
@@ -0,0 +1,9 @@
+extern crate your;
+
+use your::Matrix;
+
+#[test]
+fn display_writes_one_row_per_line() {
+    let x = Matrix::new(2, 3, &[-2, -1, 0, 1, 2, 3]);
+    assert_eq!(format!("{}", x), "-2 -1 0\n1 2 3\n");
+}
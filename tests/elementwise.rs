@@ -0,0 +1,150 @@
+extern crate your;
+
+use your::{Matrix, MatrixError};
+
+#[test]
+fn component_mul_with_mask() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let mask = Matrix::new(2, 3, &[1, 0, 1, 0, 1, 0]);
+    assert_eq!(m.component_mul(&mask), Matrix::new(2, 3, &[1, 0, 3, 0, 5, 0]));
+    assert_eq!(m.component_mul(&Matrix::ones(2, 3)), m);
+    assert_eq!(m.component_mul(&Matrix::zeros(2, 3)), Matrix::zeros(2, 3));
+}
+
+#[test]
+fn component_mul_assign_in_place() {
+    let mut m = Matrix::new(3, 2, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let weights = Matrix::new(3, 2, &[0.5, 2.0, 1.0, 0.0, -1.0, 1.0]);
+    let expected = m.component_mul(&weights);
+    m.component_mul_assign(&weights);
+    assert_eq!(m, expected);
+    assert_eq!(m, Matrix::new(3, 2, &[0.5, 4.0, 3.0, 0.0, -5.0, 6.0]));
+}
+
+#[test]
+fn checked_component_mul_mismatch() {
+    let a = Matrix::new(2, 3, &[0; 6]);
+    let b = Matrix::new(3, 2, &[0; 6]);
+    assert_eq!(a.checked_component_mul(&b),
+               Err(MatrixError::DimensionMismatch { left: (2, 3), right: (3, 2), op: "multiply element-wise" }));
+}
+
+#[test]
+#[should_panic(expected = "cannot multiply element-wise 2x3 matrix and 3x2 matrix: dimensions differ")]
+fn component_mul_panics_on_mismatch() {
+    Matrix::new(2, 3, &[0; 6]).component_mul(&Matrix::new(3, 2, &[0; 6]));
+}
+
+#[test]
+fn component_div_divides_elements() {
+    let a = Matrix::new(2, 2, &[10, 9, -8, 7]);
+    let b = Matrix::new(2, 2, &[2, 3, 4, -7]);
+    assert_eq!(a.component_div(&b), Matrix::new(2, 2, &[5, 3, -2, -1]));
+    let scaled = a.component_mul(&b);
+    assert_eq!(scaled.component_div(&b), a);
+}
+
+#[test]
+fn component_div_float_zero_propagates() {
+    let a = Matrix::new(1, 3, &[1.0, -1.0, 0.0]);
+    let q = a.component_div(&Matrix::zeros(1, 3));
+    assert_eq!(q[(0, 0)], f64::INFINITY);
+    assert_eq!(q[(0, 1)], f64::NEG_INFINITY);
+    assert!(q[(0, 2)].is_nan());
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero at (1, 0)")]
+fn component_div_integer_zero_reports_position() {
+    let a = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let b = Matrix::new(2, 2, &[1, 1, 0, 1]);
+    a.component_div(&b);
+}
+
+#[test]
+#[should_panic(expected = "cannot divide element-wise 1x2 matrix and 2x1 matrix: dimensions differ")]
+fn component_div_panics_on_mismatch() {
+    Matrix::new(1, 2, &[1, 2]).component_div(&Matrix::new(2, 1, &[1, 2]));
+}
+
+#[test]
+fn rem_element_wise_follows_rust_semantics() {
+    let a = Matrix::new(2, 2, &[7, -7, 7, -7]);
+    let b = Matrix::new(2, 2, &[3, 3, -3, -3]);
+    assert_eq!(&a % &b, Matrix::new(2, 2, &[1, -1, 1, -1]));
+    assert_eq!(a % b, Matrix::new(2, 2, &[1, -1, 1, -1]));
+}
+
+#[test]
+fn rem_by_scalar() {
+    let m = Matrix::new(2, 3, &[5, 12, -13, 20, 0, 99]);
+    assert_eq!(&m % 10, Matrix::new(2, 3, &[5, 2, -3, 0, 0, 9]));
+    assert_eq!(m % 10, Matrix::new(2, 3, &[5, 2, -3, 0, 0, 9]));
+}
+
+#[test]
+#[should_panic(expected = "cannot take the remainder of 1x2 matrix and 2x2 matrix: dimensions differ")]
+fn rem_panics_on_mismatch() {
+    let _ = Matrix::new(1, 2, &[1, 2]) % &Matrix::new(2, 2, &[1, 2, 3, 4]);
+}
+
+#[test]
+fn clamp_below_inside_and_above() {
+    let m = Matrix::new(2, 3, &[-20, 0, 100, 255, 300, 128]);
+    assert_eq!(m.clamp(0, 255), Matrix::new(2, 3, &[0, 0, 100, 255, 255, 128]));
+    let mut in_place = m;
+    in_place.clamp_mut(10, 200);
+    assert_eq!(in_place, Matrix::new(2, 3, &[10, 10, 100, 200, 200, 128]));
+}
+
+#[test]
+fn clamp_with_equal_bounds() {
+    let m = Matrix::new(1, 3, &[-1.5, 2.0, 9.0]);
+    assert_eq!(m.clamp(2.0, 2.0), Matrix::filled(1, 3, 2.0));
+}
+
+#[test]
+fn clamp_propagates_nan() {
+    let m = Matrix::new(1, 3, &[f64::NAN, -5.0, 5.0]);
+    let clamped = m.clamp(-1.0, 1.0);
+    assert!(clamped[(0, 0)].is_nan());
+    assert_eq!(clamped.row(0)[1..], [-1.0, 1.0]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "clamp bounds are reversed")]
+fn clamp_with_reversed_bounds_panics_in_debug() {
+    Matrix::new(1, 1, &[0]).clamp(5, 1);
+}
+
+#[test]
+fn abs_of_mixed_signs() {
+    let m = Matrix::new(2, 3, &[-3, 0, 4, -1, 7, -9]);
+    assert_eq!(m.abs(), Matrix::new(2, 3, &[3, 0, 4, 1, 7, 9]));
+    let floats = Matrix::new(1, 4, &[-1.5f64, -0.0, 0.0, 2.25]);
+    assert_eq!(floats.abs(), Matrix::new(1, 4, &[1.5, 0.0, 0.0, 2.25]));
+    assert!(floats.abs()[(0, 1)].is_sign_positive());
+}
+
+#[test]
+fn abs_of_error_matrix() {
+    let a = Matrix::new(2, 2, &[1.0, 4.0, -2.0, 0.5]);
+    let b = Matrix::new(2, 2, &[1.5, 3.0, 2.0, 0.5]);
+    assert_eq!((&a - &b).abs(), Matrix::new(2, 2, &[0.5, 1.0, 4.0, 0.0]));
+}
+
+#[test]
+fn checked_abs_detects_overflow() {
+    let m = Matrix::new(1, 3, &[i32::MIN + 1, 5, -5]);
+    assert_eq!(m.checked_abs(), Some(Matrix::new(1, 3, &[i32::MAX, 5, 5])));
+    assert_eq!(Matrix::new(1, 2, &[1, i32::MIN]).checked_abs(), None);
+    assert_eq!(Matrix::new(1, 1, &[f64::MIN]).checked_abs(), Some(Matrix::new(1, 1, &[f64::MAX])));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "overflow")]
+fn abs_of_i32_min_overflows_in_debug() {
+    Matrix::new(1, 1, &[i32::MIN]).abs();
+}
@@ -0,0 +1,34 @@
+extern crate your;
+
+use your::Matrix;
+
+#[test]
+fn find_matches_across_rows() {
+    let m = Matrix::new(3, 3, &[5, -1, 7, 0, 9, -2, 8, 3, 6]);
+    let found = m.find(|&value| value > 5);
+    assert_eq!(found, vec![(0, 2), (1, 1), (2, 0), (2, 2)]);
+    for &index in &found {
+        assert!(m[index] > 5);
+    }
+    assert_eq!(m.find(|&value| value < 0), vec![(0, 1), (1, 2)]);
+}
+
+#[test]
+fn find_and_position_without_matches() {
+    let m = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    assert!(m.find(|&value| value > 4).is_empty());
+    assert_eq!(m.position(|&value| value > 4), None);
+    let empty: Matrix<i32> = Matrix::new(0, 0, &[]);
+    assert!(empty.find(|_| true).is_empty());
+    assert_eq!(empty.position(|_| true), None);
+}
+
+#[test]
+fn position_returns_first_match() {
+    let m = Matrix::new(2, 3, &[1, 4, 2, 4, 9, 4]);
+    assert_eq!(m.position(|&value| value == 4), Some((0, 1)));
+    assert_eq!(m.position(|&value| value > 4), Some((1, 1)));
+    let mut calls = 0;
+    m.position(|&value| { calls += 1; value == 2 });
+    assert_eq!(calls, 3);
+}
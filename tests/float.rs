@@ -0,0 +1,81 @@
+extern crate your;
+
+use your::Matrix;
+
+fn assert_all_close(actual: &Matrix<f64>, expected: &Matrix<f64>) {
+    assert_eq!(actual.size(), expected.size());
+    for (a, e) in actual.iter().zip(expected.iter()) {
+        assert!((a - e).abs() <= 1e-12 * e.abs().max(1.0), "{} != {}", a, e);
+    }
+}
+
+#[test]
+fn sqrt_exp_ln_apply_element_wise() {
+    let m = Matrix::new(2, 2, &[1.0f64, 4.0, 0.25, 9.0]);
+    assert_eq!(m.sqrt(), Matrix::new(2, 2, &[1.0, 2.0, 0.5, 3.0]));
+    assert_all_close(&m.ln().exp(), &m);
+    assert_all_close(&m.exp().ln(), &m);
+    assert_eq!(Matrix::new(1, 2, &[0.0f64, 1.0]).exp(), Matrix::new(1, 2, &[1.0, std::f64::consts::E]));
+}
+
+#[test]
+fn negative_inputs_give_nan() {
+    let m = Matrix::new(1, 3, &[-1.0f64, 0.0, 1.0]);
+    let roots = m.sqrt();
+    assert!(roots[(0, 0)].is_nan());
+    assert_eq!((roots[(0, 1)], roots[(0, 2)]), (0.0, 1.0));
+    let logs = m.ln();
+    assert!(logs[(0, 0)].is_nan());
+    assert_eq!((logs[(0, 1)], logs[(0, 2)]), (f64::NEG_INFINITY, 0.0));
+}
+
+#[test]
+fn float_math_on_f32() {
+    let m = Matrix::new(1, 2, &[16.0f32, 1.0]);
+    assert_eq!(m.sqrt(), Matrix::new(1, 2, &[4.0, 1.0]));
+    assert_eq!(m.ln()[(0, 1)], 0.0);
+}
+
+#[test]
+fn rounding_modes_on_halves() {
+    let m = Matrix::new(1, 6, &[-2.5f64, -1.5, -0.5, 0.5, 1.5, 2.5]);
+    assert_eq!(m.round(), Matrix::new(1, 6, &[-3.0, -2.0, -1.0, 1.0, 2.0, 3.0]));
+    assert_eq!(m.floor(), Matrix::new(1, 6, &[-3.0, -2.0, -1.0, 0.0, 1.0, 2.0]));
+    assert_eq!(m.ceil(), Matrix::new(1, 6, &[-2.0, -1.0, -0.0, 1.0, 2.0, 3.0]));
+    assert_eq!(m.trunc(), Matrix::new(1, 6, &[-2.0, -1.0, -0.0, 0.0, 1.0, 2.0]));
+}
+
+#[test]
+fn round_to_decimal_places() {
+    let m = Matrix::new(2, 2, &[1.23456f64, -1.23456, 0.125, -0.125]);
+    assert_eq!(m.round_to(2), Matrix::new(2, 2, &[1.23, -1.23, 0.13, -0.13]));
+    assert_eq!(m.round_to(0), m.round());
+    assert_eq!(m.round_to(400), m);
+}
+
+#[test]
+fn round_to_leaves_integers_and_large_values_exact() {
+    let m = Matrix::new(2, 3, &[-7.0f64, 0.0, 42.0, 1e300, -9007199254740993.0, f64::MAX]);
+    assert_eq!(m.round_to(3), m);
+    assert_eq!(m.round(), m);
+    let snapped = Matrix::new(1, 3, &[0.9999999999f64, 2.0000000001, -3.0]).round_to(6);
+    assert_eq!(snapped, Matrix::new(1, 3, &[1.0, 2.0, -3.0]));
+}
+
+#[test]
+fn powi_squares_match_component_mul() {
+    let m = Matrix::new(2, 3, &[-1.5f64, 0.0, 2.0, 3.25, -4.0, 0.1]);
+    assert_eq!(m.powi(2), m.component_mul(&m));
+    assert_eq!(m.powi(0), Matrix::new(2, 3, &[1.0; 6]));
+    assert_eq!(Matrix::new(1, 2, &[2.0f64, -4.0]).powi(-1), Matrix::new(1, 2, &[0.5, -0.25]));
+}
+
+#[test]
+fn powf_with_fractional_exponent() {
+    let m = Matrix::new(1, 3, &[4.0f64, 0.0, 9.0]);
+    assert_all_close(&m.powf(0.5), &m.sqrt());
+    assert_eq!(Matrix::new(1, 2, &[-8.0f64, -2.0]).powf(2.0), Matrix::new(1, 2, &[64.0, 4.0]));
+    let mixed = Matrix::new(1, 2, &[-8.0f64, 8.0]).powf(1.0 / 3.0);
+    assert!(mixed[(0, 0)].is_nan());
+    assert!((mixed[(0, 1)] - 2.0).abs() < 1e-15);
+}
@@ -0,0 +1,169 @@
+extern crate your;
+
+use your::{Matrix, MatrixError};
+
+#[test]
+fn index_reads_corner_elements() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(m[(0, 0)], 1);
+    assert_eq!(m[(0, 2)], 3);
+    assert_eq!(m[(1, 0)], 4);
+    assert_eq!(m[(1, 2)], 6);
+}
+
+#[test]
+fn index_mut_round_trip() {
+    let mut m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    m[(1, 2)] = 60;
+    m[(0, 1)] = 20;
+    assert_eq!(m[(1, 2)], 60);
+    assert_eq!(m[(0, 1)], 20);
+    assert_eq!(m, Matrix::new(2, 3, &[1, 20, 3, 4, 5, 60]));
+}
+
+#[test]
+#[should_panic(expected = "index (2, 0) out of bounds for 2x3 matrix")]
+fn index_out_of_bounds_row() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let _ = m[(2, 0)];
+}
+
+#[test]
+#[should_panic(expected = "index (0, 3) out of bounds for 2x3 matrix")]
+fn index_mut_out_of_bounds_col() {
+    let mut m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    m[(0, 3)] = 0;
+}
+
+#[test]
+fn get_returns_row_major_element() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(m.get(0, 0), Some(&1));
+    assert_eq!(m.get(1, 1), Some(&5));
+    assert_eq!(m.get(1, 2), Some(&6));
+}
+
+#[test]
+fn get_out_of_bounds_is_none() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(m.get(2, 0), None);
+    // (0, 3) would alias (1, 0) in the flat buffer.
+    assert_eq!(m.get(0, 3), None);
+    assert_eq!(m.get(usize::MAX, 0), None);
+}
+
+#[test]
+fn get_mut_writes_through() {
+    let mut m = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    *m.get_mut(1, 0).unwrap() = 30;
+    assert_eq!(m.get_mut(2, 0), None);
+    assert_eq!(m.get_mut(0, 2), None);
+    assert_eq!(m, Matrix::new(2, 2, &[1, 2, 30, 4]));
+}
+
+#[test]
+fn index_by_row_returns_slice() {
+    let m = Matrix::new(3, 2, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(&m[0], &[1, 2]);
+    assert_eq!(&m[2], &[5, 6]);
+    assert_eq!(m[1][1], 4);
+    assert_eq!(m[1][0], m[(1, 0)]);
+}
+
+#[test]
+fn index_mut_by_row_writes_through() {
+    let mut m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    m[1][2] = 60;
+    for value in m[0].iter_mut() {
+        *value *= 10;
+    }
+    assert_eq!(m, Matrix::new(2, 3, &[10, 20, 30, 4, 5, 60]));
+}
+
+#[test]
+#[should_panic(expected = "row index 2 out of bounds for 2x3 matrix")]
+fn index_by_row_out_of_bounds() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let _ = &m[2];
+}
+
+#[test]
+fn row_and_column_extraction() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(m.row(1), &[4, 5, 6]);
+    assert_eq!(m.column(0), vec![1, 4]);
+    assert_eq!(m.column(2), vec![3, 6]);
+}
+
+#[test]
+fn row_and_column_of_row_vector() {
+    let m = Matrix::new(1, 4, &[1, 2, 3, 4]);
+    assert_eq!(m.row(0), &[1, 2, 3, 4]);
+    assert_eq!(m.column(3), vec![4]);
+}
+
+#[test]
+fn row_and_column_of_column_vector() {
+    let m = Matrix::new(4, 1, &[1, 2, 3, 4]);
+    assert_eq!(m.row(2), &[3]);
+    assert_eq!(m.column(0), vec![1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "column index 3 out of bounds for 2x3 matrix")]
+fn column_out_of_bounds() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    m.column(3);
+}
+
+#[test]
+#[should_panic(expected = "row index 1 out of bounds for 1x4 matrix")]
+fn row_out_of_bounds() {
+    let m = Matrix::new(1, 4, &[1, 2, 3, 4]);
+    m.row(1);
+}
+
+#[test]
+fn diagonal_of_square_and_rectangular() {
+    let square = Matrix::new(3, 3, &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(square.diagonal(), vec![1, 5, 9]);
+    let wide = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(wide.diagonal(), vec![1, 5]);
+    let tall = Matrix::new(3, 1, &[7, 8, 9]);
+    assert_eq!(tall.diagonal(), vec![7]);
+}
+
+#[test]
+fn set_diagonal_writes_onto_diagonal() {
+    let mut m = Matrix::new(3, 2, &[1, 2, 3, 4, 5, 6]);
+    m.set_diagonal(&[10, 40]).unwrap();
+    assert_eq!(m, Matrix::new(3, 2, &[10, 2, 3, 40, 5, 6]));
+}
+
+#[test]
+fn set_diagonal_rejects_wrong_length() {
+    let mut m = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    assert_eq!(m.set_diagonal(&[1, 2, 3]), Err(MatrixError::LengthMismatch { expected: 2, actual: 3 }));
+    assert_eq!(m, Matrix::new(2, 2, &[1, 2, 3, 4]));
+}
+
+#[test]
+fn trace_sums_diagonal() {
+    assert_eq!(Matrix::new(1, 1, &[-7]).trace(), -7);
+    let m = Matrix::new(3, 3, &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(m.trace(), 15);
+    assert_eq!(Matrix::<i32>::new(0, 0, &[]).trace(), 0);
+}
+
+#[test]
+fn trace_is_linear() {
+    let a = Matrix::new(2, 2, &[1.5, 2.0, -3.0, 4.0]);
+    let b = Matrix::new(2, 2, &[0.5, 9.0, 9.0, -1.0]);
+    assert_eq!((&a + &b).trace(), a.trace() + b.trace());
+}
+
+#[test]
+#[should_panic(expected = "cannot take the trace of 2x3 matrix: matrix is not square")]
+fn trace_rejects_non_square() {
+    Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]).trace();
+}
@@ -0,0 +1,150 @@
+extern crate your;
+
+use your::Matrix;
+
+#[test]
+fn rows_yields_each_row() {
+    let m = Matrix::new(3, 2, &[1, 2, 3, 4, 5, 6]);
+    let rows: Vec<&[i32]> = m.rows().collect();
+    assert_eq!(rows, vec![&[1, 2][..], &[3, 4][..], &[5, 6][..]]);
+}
+
+#[test]
+fn rows_is_exact_size_and_double_ended() {
+    let m = Matrix::new(3, 2, &[1, 2, 3, 4, 5, 6]);
+    let mut rows = m.rows();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows.next_back(), Some(&[5, 6][..]));
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows.next(), Some(&[1, 2][..]));
+    assert_eq!(rows.next_back(), Some(&[3, 4][..]));
+    assert_eq!(rows.next(), None);
+    assert_eq!(rows.next_back(), None);
+}
+
+#[test]
+fn rows_of_zero_width_matrix() {
+    let m: Matrix<i32> = Matrix::new(2, 0, &[]);
+    let rows: Vec<&[i32]> = m.rows().collect();
+    assert_eq!(rows.len(), 2);
+    assert!(rows.iter().all(|row| row.is_empty()));
+}
+
+#[test]
+fn rows_mut_normalizes_in_place() {
+    let mut m = Matrix::new(2, 2, &[1.0, 3.0, 2.0, 2.0]);
+    for row in m.rows_mut() {
+        let sum: f64 = row.iter().sum();
+        for value in row.iter_mut() {
+            *value /= sum;
+        }
+    }
+    assert_eq!(m, Matrix::new(2, 2, &[0.25, 0.75, 0.5, 0.5]));
+}
+
+#[test]
+fn rows_mut_from_both_ends() {
+    let mut m = Matrix::new(3, 2, &[1, 2, 3, 4, 5, 6]);
+    {
+        let mut rows = m.rows_mut();
+        assert_eq!(rows.len(), 3);
+        rows.next_back().unwrap()[0] = 50;
+        rows.next().unwrap()[1] = 20;
+        rows.next().unwrap()[0] = 30;
+        assert!(rows.next_back().is_none());
+    }
+    assert_eq!(m, Matrix::new(3, 2, &[1, 20, 30, 4, 50, 6]));
+}
+
+#[test]
+fn columns_yields_each_column() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let columns: Vec<Vec<i32>> = m.columns().map(|c| c.cloned().collect()).collect();
+    assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    for (j, column) in m.columns().enumerate() {
+        assert_eq!(column.cloned().collect::<Vec<_>>(), m.column(j));
+    }
+}
+
+#[test]
+fn columns_are_exact_size() {
+    let m = Matrix::new(3, 2, &[1, 2, 3, 4, 5, 6]);
+    let mut columns = m.columns();
+    assert_eq!(columns.len(), 2);
+    let mut last = columns.next_back().unwrap();
+    assert_eq!(columns.len(), 1);
+    assert_eq!(last.len(), 3);
+    assert_eq!(last.next_back(), Some(&6));
+    assert_eq!(last.next(), Some(&2));
+    assert_eq!(last.len(), 1);
+}
+
+#[test]
+fn columns_per_column_minimum() {
+    let m = Matrix::new(3, 3, &[4, -1, 7, 2, 5, 7, 9, 0, -3]);
+    let minima: Vec<i32> = m.columns().map(|c| *c.min().unwrap()).collect();
+    assert_eq!(minima, vec![2, -1, -3]);
+}
+
+#[test]
+fn iter_visits_row_major_order() {
+    let m = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    assert_eq!(m.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    assert_eq!(m.iter().sum::<i32>(), 10);
+    assert_eq!(m.iter().len(), 4);
+}
+
+#[test]
+fn for_loops_over_matrix_references() {
+    let mut m = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    for v in &mut m {
+        *v *= 2;
+    }
+    let mut seen = Vec::new();
+    for v in &m {
+        seen.push(*v);
+    }
+    assert_eq!(seen, vec![2, 4, 6, 8]);
+    for v in m.iter_mut().rev().take(1) {
+        *v = 0;
+    }
+    assert_eq!(m, Matrix::new(2, 2, &[2, 4, 6, 0]));
+}
+
+#[test]
+fn into_iter_consumes_matrix() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let mut iter = m.into_iter();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.next_back(), Some(6));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn indexed_iter_matches_index_on_non_square() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let triples: Vec<(usize, usize, i32)> = m.indexed_iter().map(|(i, j, v)| (i, j, *v)).collect();
+    assert_eq!(triples, vec![(0, 0, 1), (0, 1, 2), (0, 2, 3), (1, 0, 4), (1, 1, 5), (1, 2, 6)]);
+    for (i, j, v) in m.indexed_iter() {
+        assert_eq!(m[(i, j)], *v);
+    }
+    assert_eq!(m.indexed_iter().next_back(), Some((1, 2, &6)));
+}
+
+#[test]
+fn indexed_iter_finds_max_coordinates() {
+    let m = Matrix::new(3, 2, &[1, 9, 3, 4, 8, 2]);
+    let (i, j, _) = m.indexed_iter().max_by_key(|&(_, _, v)| *v).unwrap();
+    assert_eq!((i, j), (0, 1));
+}
+
+#[test]
+fn indexed_iter_mut_zeroes_above_diagonal() {
+    let mut m = Matrix::new(3, 4, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+    for (i, j, v) in m.indexed_iter_mut() {
+        if j > i {
+            *v = 0;
+        }
+    }
+    assert_eq!(m, Matrix::new(3, 4, &[1, 0, 0, 0, 5, 6, 0, 0, 9, 10, 11, 0]));
+}
@@ -0,0 +1,861 @@
+extern crate your;
+
+use your::{Matrix, MatrixError};
+
+fn assert_close(actual: f64, expected: f64, tol: f64) {
+    assert!((actual - expected).abs() <= tol, "{} is not within {} of {}", actual, tol, expected);
+}
+
+fn assert_matrix_close(actual: &Matrix<f64>, expected: &Matrix<f64>, tol: f64) {
+    assert_eq!(actual.size(), expected.size());
+    for (i, j, &value) in actual.indexed_iter() {
+        assert!((value - expected[(i, j)]).abs() <= tol,
+                "element ({}, {}): {} is not within {} of {}", i, j, value, tol, expected[(i, j)]);
+    }
+}
+
+fn residual_norm(a: &Matrix<f64>, x: &[f64], b: &[f64]) -> f64 {
+    a.mul_vec(x).iter().zip(b.iter()).map(|(ax, b)| (ax - b) * (ax - b)).sum::<f64>().sqrt()
+}
+
+/// Deterministic pseudo-random, diagonally dominant (hence well-conditioned)
+/// `n` x `n` matrix.
+fn well_conditioned(n: usize, seed: u64) -> Matrix<f64> {
+    let mut state = seed;
+    let mut m = Matrix::from_fn(n, n, |_, _| {
+        state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+        (state >> 33) as f64 / (1u64 << 31) as f64 - 0.5
+    });
+    for i in 0..n {
+        m[(i, i)] += n as f64;
+    }
+    m
+}
+
+#[test]
+fn det_of_known_matrices() {
+    assert_close(Matrix::new(2, 2, &[3.0, 8.0, 4.0, 6.0]).det().unwrap(), -14.0, 1e-12);
+    let m = Matrix::new(3, 3, &[6.0, 1.0, 1.0, 4.0, -2.0, 5.0, 2.0, 8.0, 7.0]);
+    assert_close(m.det().unwrap(), -306.0, 1e-9);
+    // The first pivot is zero, so a row swap flips the sign.
+    let swapped = Matrix::new(2, 2, &[0.0, 1.0, 1.0, 0.0]);
+    assert_close(swapped.det().unwrap(), -1.0, 0.0);
+}
+
+#[test]
+fn det_of_identity_and_singular() {
+    assert_eq!(Matrix::<f64>::identity(4).det(), Ok(1.0));
+    let singular = Matrix::new(3, 3, &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0]);
+    assert_eq!(singular.det(), Ok(0.0));
+}
+
+#[test]
+fn det_is_multiplicative() {
+    let a = Matrix::new(3, 3, &[2.0, -1.0, 0.5, 1.0, 3.0, 2.0, 0.0, 1.0, -4.0]);
+    let b = Matrix::new(3, 3, &[1.0, 2.0, 0.0, 0.0, 1.0, 5.0, 3.0, -2.0, 1.0]);
+    let product = (&a * &b).det().unwrap();
+    assert_close(product, a.det().unwrap() * b.det().unwrap(), 1e-9);
+}
+
+#[test]
+fn det_rejects_non_square() {
+    let err = Matrix::new(2, 3, &[0.0; 6]).det().unwrap_err();
+    assert_eq!(err, MatrixError::NotSquare { shape: (2, 3), op: "take the determinant of" });
+    assert_eq!(err.to_string(), "cannot take the determinant of 2x3 matrix: matrix is not square");
+}
+
+#[test]
+fn inverse_of_known_matrix() {
+    let a = Matrix::new(2, 2, &[4.0, 7.0, 2.0, 6.0]);
+    let expected = Matrix::new(2, 2, &[0.6, -0.7, -0.2, 0.4]);
+    assert_matrix_close(&a.inverse().unwrap(), &expected, 1e-12);
+}
+
+#[test]
+fn inverse_times_matrix_is_identity() {
+    for (n, seed) in [(1, 1), (2, 2), (3, 3), (5, 4), (8, 5)].iter().cloned() {
+        let a = well_conditioned(n, seed);
+        let inverse = a.inverse().unwrap();
+        assert_matrix_close(&(&a * &inverse), &Matrix::identity(n), 1e-10);
+        assert_matrix_close(&(&inverse * &a), &Matrix::identity(n), 1e-10);
+    }
+}
+
+#[test]
+fn inverse_of_singular_matrix_errors() {
+    let singular = Matrix::new(3, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    assert_eq!(singular.inverse(), Err(MatrixError::Singular));
+    assert_eq!(Matrix::<f64>::zeros(2, 2).inverse(), Err(MatrixError::Singular));
+}
+
+#[test]
+fn inverse_of_non_square_errors() {
+    assert_eq!(Matrix::new(1, 2, &[1.0, 2.0]).inverse(),
+               Err(MatrixError::NotSquare { shape: (1, 2), op: "invert" }));
+}
+
+#[test]
+fn cholesky_reconstructs_matrix() {
+    let a = Matrix::new(3, 3, &[4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0]);
+    let l = a.cholesky().unwrap();
+    assert_matrix_close(&l, &Matrix::new(3, 3, &[2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]), 1e-12);
+    assert_matrix_close(&(&l * l.transpose()), &a, 1e-12);
+}
+
+#[test]
+fn cholesky_of_random_spd_matrix() {
+    let b = well_conditioned(6, 11);
+    let a = &b * b.transpose();
+    let l = a.cholesky().unwrap();
+    assert_matrix_close(&(&l * l.transpose()), &a, 1e-10);
+}
+
+#[test]
+fn cholesky_rejects_indefinite_matrix() {
+    let indefinite = Matrix::new(2, 2, &[1.0, 2.0, 2.0, 1.0]);
+    assert_eq!(indefinite.cholesky(), Err(MatrixError::NotPositiveDefinite));
+    let negative = Matrix::new(1, 1, &[-4.0]);
+    assert_eq!(negative.cholesky(), Err(MatrixError::NotPositiveDefinite));
+    let semidefinite = Matrix::new(2, 2, &[1.0, 1.0, 1.0, 1.0]);
+    assert_eq!(semidefinite.cholesky(), Err(MatrixError::NotPositiveDefinite));
+}
+
+#[test]
+fn solve_known_system() {
+    // 2x + y - z = 8, -3x - y + 2z = -11, -2x + y + 2z = -3
+    let a = Matrix::new(3, 3, &[2.0, 1.0, -1.0, -3.0, -1.0, 2.0, -2.0, 1.0, 2.0]);
+    let x = a.solve(&[8.0, -11.0, -3.0]).unwrap();
+    for (actual, expected) in x.iter().zip([2.0, 3.0, -1.0].iter()) {
+        assert_close(*actual, *expected, 1e-12);
+    }
+}
+
+#[test]
+fn solve_random_systems_have_small_residuals() {
+    for seed in 0..5 {
+        let n = 4 + seed as usize;
+        let a = well_conditioned(n, seed);
+        let b: Vec<f64> = (0..n).map(|i| i as f64 - 2.0).collect();
+        let x = a.solve(&b).unwrap();
+        assert!(residual_norm(&a, &x, &b) < 1e-10);
+    }
+}
+
+#[test]
+fn solve_error_paths() {
+    let singular = Matrix::new(2, 2, &[1.0, 2.0, 2.0, 4.0]);
+    assert_eq!(singular.solve(&[1.0, 2.0]), Err(MatrixError::Singular));
+    let a = Matrix::<f64>::identity(3);
+    assert_eq!(a.solve(&[1.0, 2.0]), Err(MatrixError::LengthMismatch { expected: 3, actual: 2 }));
+    assert_eq!(Matrix::new(1, 2, &[1.0, 2.0]).solve(&[1.0]),
+               Err(MatrixError::NotSquare { shape: (1, 2), op: "solve a system with" }));
+}
+
+#[test]
+fn rank_of_identity_and_zero() {
+    assert_eq!(Matrix::<f64>::identity(4).rank(), 4);
+    assert_eq!(Matrix::<f64>::zeros(3, 5).rank(), 0);
+    assert_eq!(Matrix::<f64>::new(0, 0, &[]).rank(), 0);
+}
+
+#[test]
+fn rank_drops_for_duplicated_row() {
+    let m = Matrix::new(3, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 1.0, 2.0, 3.0]);
+    assert_eq!(m.rank(), 2);
+    let dependent = Matrix::new(3, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    assert_eq!(dependent.rank(), 2);
+}
+
+#[test]
+fn rank_of_rectangular_matrices() {
+    let wide = Matrix::new(2, 4, &[1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.1]);
+    assert_eq!(wide.rank(), 2);
+    let tall = Matrix::new(4, 2, &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0, 4.0, 8.0]);
+    assert_eq!(tall.rank(), 1);
+}
+
+#[test]
+fn rank_with_tolerance() {
+    let nearly = Matrix::new(2, 2, &[1.0, 1.0, 1.0, 1.0 + 1e-9]);
+    assert_eq!(nearly.rank(), 2);
+    assert_eq!(nearly.rank_with_tol(1e-6), 1);
+}
+
+#[test]
+fn rref_of_known_matrices() {
+    let m = Matrix::new(3, 4, &[1.0, 2.0, -1.0, -4.0,
+                                2.0, 3.0, -1.0, -11.0,
+                                -2.0, 0.0, -3.0, 22.0]);
+    let expected = Matrix::new(3, 4, &[1.0, 0.0, 0.0, -8.0,
+                                       0.0, 1.0, 0.0, 1.0,
+                                       0.0, 0.0, 1.0, -2.0]);
+    assert_matrix_close(&m.rref(), &expected, 1e-12);
+    assert_eq!(Matrix::<f64>::identity(3).rref(), Matrix::identity(3));
+}
+
+#[test]
+fn rref_of_rank_deficient_and_rectangular_matrices() {
+    let m = Matrix::new(3, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    let (reduced, pivots) = m.rref_with_pivots();
+    assert_eq!(pivots, vec![0, 1]);
+    let expected = Matrix::new(3, 3, &[1.0, 0.0, -1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0]);
+    assert_matrix_close(&reduced, &expected, 1e-12);
+    assert_eq!(reduced.row(2), vec![0.0, 0.0, 0.0]);
+
+    let wide = Matrix::new(2, 4, &[0.0, 2.0, 4.0, 2.0, 0.0, 1.0, 2.0, 3.0]);
+    let (reduced, pivots) = wide.rref_with_pivots();
+    assert_eq!(pivots, vec![1, 3]);
+    assert_matrix_close(&reduced, &Matrix::new(2, 4, &[0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 0.0, 1.0]), 1e-12);
+
+    assert_eq!(Matrix::<f64>::zeros(2, 3).rref(), Matrix::zeros(2, 3));
+}
+
+#[test]
+fn rref_treats_near_zero_pivots_as_zero() {
+    let m = Matrix::new(2, 2, &[1.0, 2.0, 2.0, 4.0 + 1e-17]);
+    let (reduced, pivots) = m.rref_with_pivots();
+    assert_eq!(pivots, vec![0]);
+    assert_eq!(reduced, Matrix::new(2, 2, &[1.0, 2.0, 0.0, 0.0]));
+}
+
+#[test]
+fn rref_is_idempotent() {
+    let matrices = vec![
+        Matrix::new(3, 4, &[1.0, 2.0, -1.0, -4.0, 2.0, 3.0, -1.0, -11.0, -2.0, 0.0, -3.0, 22.0]),
+        Matrix::new(3, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+        Matrix::new(4, 2, &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0, 4.0, 8.0]),
+        well_conditioned(5, 3),
+    ];
+    for m in matrices {
+        let once = m.rref();
+        assert_eq!(once.rref(), once);
+    }
+}
+
+#[test]
+fn norms_with_negative_entries() {
+    let m = Matrix::new(2, 3, &[1.0, -2.0, 3.0, -4.0, 5.0, -6.0]);
+    assert_close(m.norm_frobenius(), 91.0f64.sqrt(), 1e-12);
+    assert_eq!(m.norm_l1(), 9.0);
+    assert_eq!(m.norm_inf(), 15.0);
+    assert_eq!(m.norm_max(), 6.0);
+}
+
+#[test]
+fn norms_of_identity() {
+    let id = Matrix::<f64>::identity(4);
+    assert_eq!(id.norm_frobenius(), 2.0);
+    assert_eq!(id.norm_l1(), 1.0);
+    assert_eq!(id.norm_inf(), 1.0);
+    assert_eq!(id.norm_max(), 1.0);
+}
+
+#[test]
+fn norms_of_empty_matrices() {
+    for &(row, col) in &[(0, 0), (0, 3), (3, 0)] {
+        let empty = Matrix::<f64>::zeros(row, col);
+        assert_eq!(empty.norm_frobenius(), 0.0);
+        assert_eq!(empty.norm_l1(), 0.0);
+        assert_eq!(empty.norm_inf(), 0.0);
+        assert_eq!(empty.norm_max(), 0.0);
+    }
+}
+
+fn symmetric(n: usize, seed: u64) -> Matrix<f64> {
+    let b = well_conditioned(n, seed);
+    &b + b.transpose()
+}
+
+#[test]
+fn eig_sym_of_diagonal_matrix_is_exact() {
+    let d = Matrix::from_diag(&[3.0, -1.0, 2.0, 0.5]);
+    let (values, vectors) = d.eig_sym().unwrap();
+    assert_eq!(values, vec![-1.0, 0.5, 2.0, 3.0]);
+    assert_eq!(&vectors * Matrix::from_diag(&values) * vectors.transpose(), d);
+}
+
+#[test]
+fn eig_sym_of_known_matrix() {
+    let m = Matrix::new(2, 2, &[2.0, 1.0, 1.0, 2.0]);
+    let (values, vectors) = m.eig_sym().unwrap();
+    assert_close(values[0], 1.0, 1e-12);
+    assert_close(values[1], 3.0, 1e-12);
+    let v = vectors.column(1);
+    assert_close(v[0].abs(), 0.5f64.sqrt(), 1e-12);
+    assert_close(v[0], v[1], 1e-12);
+}
+
+#[test]
+fn eig_sym_reconstructs_random_symmetric_matrices() {
+    for seed in 0..5 {
+        let n = 3 + seed as usize;
+        let a = symmetric(n, seed);
+        let (values, vectors) = a.eig_sym().unwrap();
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+        let reconstructed = &vectors * Matrix::from_diag(&values) * vectors.transpose();
+        assert_matrix_close(&reconstructed, &a, 1e-10);
+        assert_matrix_close(&(vectors.transpose() * &vectors), &Matrix::identity(n), 1e-12);
+    }
+}
+
+#[test]
+fn eig_sym_error_paths() {
+    let op = "take the symmetric eigendecomposition of";
+    let m = Matrix::new(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(m.eig_sym(), Err(MatrixError::NotSymmetric { at: (0, 1), op }));
+    assert_eq!(Matrix::<f64>::zeros(2, 3).eig_sym(), Err(MatrixError::NotSquare { shape: (2, 3), op }));
+    let (values, vectors) = Matrix::<f64>::new(0, 0, &[]).eig_sym().unwrap();
+    assert!(values.is_empty());
+    assert_eq!(vectors.size(), (0, 0));
+}
+
+fn assert_svd(a: &Matrix<f64>) {
+    let (u, values, vt) = a.svd().unwrap();
+    let (row, col) = a.size();
+    let k = row.min(col);
+    assert_eq!(u.size(), (row, k));
+    assert_eq!(values.len(), k);
+    assert_eq!(vt.size(), (k, col));
+    assert!(values.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert!(values.iter().all(|&value| value >= 0.0));
+    assert_matrix_close(&(&u * Matrix::from_diag(&values) * &vt), a, 1e-10);
+    assert_matrix_close(&(u.transpose() * &u), &Matrix::identity(k), 1e-10);
+    assert_matrix_close(&(&vt * vt.transpose()), &Matrix::identity(k), 1e-10);
+}
+
+#[test]
+fn svd_of_known_matrix() {
+    let m = Matrix::new(2, 2, &[3.0, 0.0, 4.0, 5.0]);
+    let (_, values, _) = m.svd().unwrap();
+    assert_close(values[0], 45.0f64.sqrt(), 1e-12);
+    assert_close(values[1], 5.0f64.sqrt(), 1e-12);
+    assert_svd(&m);
+}
+
+#[test]
+fn svd_of_square_tall_and_wide_matrices() {
+    assert_svd(&well_conditioned(5, 2));
+    let tall = Matrix::from_fn(6, 3, |i, j| ((i * 7 + j * 3) % 5) as f64 - 2.0);
+    assert_svd(&tall);
+    assert_svd(&tall.transpose());
+}
+
+#[test]
+fn svd_of_rank_deficient_matrices() {
+    let m = Matrix::new(3, 2, &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+    let (_, values, _) = m.svd().unwrap();
+    assert_close(values[0], 70.0f64.sqrt(), 1e-12);
+    assert_close(values[1], 0.0, 1e-12);
+    assert_svd(&m);
+    assert_svd(&Matrix::new(3, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]));
+    assert_svd(&Matrix::zeros(3, 2));
+    assert_svd(&Matrix::zeros(2, 4));
+}
+
+#[test]
+fn pinv_of_invertible_matrix_is_inverse() {
+    let a = well_conditioned(4, 5);
+    assert_matrix_close(&a.pinv(), &a.inverse().unwrap(), 1e-10);
+}
+
+#[test]
+fn pinv_of_tall_full_rank_matrix_is_left_inverse() {
+    let tall = Matrix::from_fn(5, 3, |i, j| ((i + 1) as f64).powi(j as i32));
+    let pinv = tall.pinv();
+    assert_eq!(pinv.size(), (3, 5));
+    assert_matrix_close(&(&pinv * &tall), &Matrix::identity(3), 1e-10);
+}
+
+#[test]
+fn pinv_satisfies_penrose_conditions() {
+    let matrices = vec![
+        Matrix::new(3, 2, &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0]),
+        Matrix::new(2, 4, &[1.0, 0.0, 2.0, -1.0, 0.0, 1.0, 1.0, 3.0]),
+        Matrix::new(3, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+        Matrix::zeros(2, 3),
+    ];
+    for a in matrices {
+        let p = a.pinv();
+        assert_matrix_close(&(&a * &p * &a), &a, 1e-10);
+        assert_matrix_close(&(&p * &a * &p), &p, 1e-10);
+    }
+}
+
+#[test]
+fn lstsq_fits_a_line_to_noisy_data() {
+    let xs: Vec<f64> = (0..20).map(|i| i as f64 * 0.5).collect();
+    let noise = [0.05, -0.03, 0.02, -0.04, 0.01, 0.03, -0.05, 0.02, -0.01, 0.04,
+                 -0.02, 0.03, -0.04, 0.05, -0.03, 0.01, 0.02, -0.05, 0.04, -0.01];
+    let ys: Vec<f64> = xs.iter().zip(noise.iter()).map(|(x, e)| 1.5 + 2.0 * x + e).collect();
+    let a = Matrix::from_fn(xs.len(), 2, |i, j| if j == 0 { 1.0 } else { xs[i] });
+    let coefficients = a.lstsq(&ys).unwrap();
+    assert_close(coefficients[0], 1.5, 0.05);
+    assert_close(coefficients[1], 2.0, 0.01);
+}
+
+#[test]
+fn lstsq_residual_is_orthogonal_to_column_space() {
+    let a = Matrix::from_fn(6, 3, |i, j| ((i + 1) as f64).powi(j as i32));
+    let b = [1.0, -2.0, 0.5, 3.0, -1.0, 2.0];
+    let x = a.lstsq(&b).unwrap();
+    let fitted = a.mul_vec(&x);
+    let residual: Vec<f64> = b.iter().zip(fitted.iter()).map(|(b, f)| b - f).collect();
+    for value in a.vec_mul(&residual) {
+        assert_close(value, 0.0, 1e-10);
+    }
+}
+
+#[test]
+fn lstsq_matches_solve_for_square_systems() {
+    let a = well_conditioned(5, 9);
+    let b = [1.0, 2.0, 3.0, 4.0, 5.0];
+    let x = a.lstsq(&b).unwrap();
+    for (actual, expected) in x.iter().zip(a.solve(&b).unwrap().iter()) {
+        assert_close(*actual, *expected, 1e-10);
+    }
+}
+
+#[test]
+fn lstsq_error_paths() {
+    let a = Matrix::new(3, 2, &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+    assert_eq!(a.lstsq(&[1.0, 2.0, 3.0]), Err(MatrixError::RankDeficient));
+    assert_eq!(a.lstsq(&[1.0, 2.0]), Err(MatrixError::LengthMismatch { expected: 3, actual: 2 }));
+    let wide = Matrix::new(1, 2, &[1.0, 2.0]);
+    assert_eq!(wide.lstsq(&[1.0]), Err(MatrixError::RankDeficient));
+}
+
+#[test]
+fn cofactors_of_known_matrix() {
+    let m = Matrix::new(3, 3, &[1i64, 2, 3, 0, 4, 5, 1, 0, 6]);
+    assert_eq!(m.cofactor(0, 0), 24);
+    assert_eq!(m.cofactor(0, 1), 5);
+    assert_eq!(m.cofactor(1, 2), 2);
+    assert_eq!(m.cofactor_matrix(), Matrix::new(3, 3, &[24, 5, -4, -12, 3, 2, -2, -5, 4]));
+    assert_eq!(m.adjugate(), m.cofactor_matrix().transpose());
+}
+
+#[test]
+fn adjugate_times_matrix_is_det_times_identity() {
+    let matrices = vec![
+        (Matrix::new(2, 2, &[3i64, 7, -2, 5]), 29),
+        (Matrix::new(3, 3, &[1i64, 2, 3, 0, 4, 5, 1, 0, 6]), 22),
+        (Matrix::new(3, 3, &[1i64, 2, 3, 4, 5, 6, 7, 8, 9]), 0),
+        (Matrix::new(4, 4, &[0i64, 2, 1, 3, 1, 0, 2, 1, 3, 1, 0, 2, 2, 3, 1, 0]), -62),
+    ];
+    for (m, det) in matrices {
+        let n = m.size().0;
+        let expected = Matrix::identity(n) * det;
+        assert_eq!(&m * m.adjugate(), expected);
+        assert_eq!(m.adjugate() * &m, expected);
+    }
+}
+
+#[test]
+fn adjugate_of_small_and_float_matrices() {
+    assert_eq!(Matrix::new(1, 1, &[7i32]).adjugate(), Matrix::new(1, 1, &[1]));
+    let a = well_conditioned(4, 1);
+    let det = a.det().unwrap();
+    assert_matrix_close(&a.adjugate(), &(a.inverse().unwrap() * det), 1e-8);
+}
+
+#[test]
+#[should_panic(expected = "cannot take the adjugate of 2x3 matrix: matrix is not square")]
+fn adjugate_of_non_square_matrix_panics() {
+    Matrix::<i64>::zeros(2, 3).adjugate();
+}
+
+#[test]
+fn cond_of_identity_is_one() {
+    let id = Matrix::<f64>::identity(5);
+    assert_close(id.cond(), 1.0, 1e-12);
+    assert_close(id.cond_1(), 1.0, 1e-12);
+}
+
+#[test]
+fn cond_of_nearly_singular_matrix_is_large() {
+    let m = Matrix::new(2, 2, &[1.0, 1.0, 1.0, 1.0 + 1e-10]);
+    assert!(m.cond() > 1e9);
+    assert!(m.cond_1() > 1e9);
+    let singular = Matrix::new(2, 2, &[1.0, 2.0, 2.0, 4.0]);
+    assert_eq!(singular.cond(), f64::INFINITY);
+    assert_eq!(singular.cond_1(), f64::INFINITY);
+}
+
+#[test]
+fn cond_is_invariant_under_scaling() {
+    let a = well_conditioned(5, 4);
+    let cond = a.cond();
+    assert_close((&a * 1000.0).cond(), cond, 1e-9 * cond);
+    assert_close((&a * -0.001).cond(), cond, 1e-9 * cond);
+}
+
+#[test]
+fn cond_1_estimate_matches_exact_norm() {
+    for seed in 0..5 {
+        let a = well_conditioned(4 + seed as usize, seed);
+        let exact = a.norm_l1() * a.inverse().unwrap().norm_l1();
+        let estimate = a.cond_1();
+        assert!(estimate <= exact * (1.0 + 1e-12));
+        assert!(estimate >= exact / 3.0);
+    }
+    let m = Matrix::new(2, 2, &[4.0, 1.0, 2.0, 3.0]);
+    assert_close(m.cond_1(), 6.0 * 0.5, 1e-12);
+}
+
+#[test]
+fn expm_of_zero_is_identity() {
+    assert_eq!(Matrix::<f64>::zeros(3, 3).expm(), Matrix::identity(3));
+}
+
+#[test]
+fn expm_of_empty_matrix_is_empty_identity() {
+    let empty: Matrix<f64> = Matrix::new(0, 0, &[]);
+    assert_eq!(empty.expm(), Matrix::identity(0));
+}
+
+#[test]
+fn expm_of_non_finite_matrix_is_nan() {
+    for &value in &[f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+        let m = Matrix::new(2, 2, &[1.0, value, 0.0, 2.0]);
+        let e = m.expm();
+        assert_eq!(e.size(), (2, 2));
+        assert!(e.iter().all(|x| x.is_nan()));
+    }
+}
+
+#[test]
+fn expm_of_overflowing_norm_does_not_panic() {
+    let m = Matrix::new(2, 2, &[f64::MAX, f64::MAX, 0.0, 0.0]);
+    assert_eq!(m.expm().size(), (2, 2));
+}
+
+#[test]
+fn expm_of_diagonal_matrix() {
+    let d = [1.0, -2.0, 0.5, 3.0];
+    let e = Matrix::from_diag(&d).expm();
+    let expected = Matrix::from_diag(&d.iter().map(|x: &f64| x.exp()).collect::<Vec<_>>());
+    for (actual, expected) in e.iter().zip(expected.iter()) {
+        assert_close(*actual, *expected, 1e-12 * expected.abs().max(1.0));
+    }
+}
+
+#[test]
+fn expm_of_rotation_generator() {
+    let theta = 0.75f64;
+    let generator = Matrix::new(2, 2, &[0.0, -theta, theta, 0.0]);
+    let expected = Matrix::new(2, 2, &[theta.cos(), -theta.sin(), theta.sin(), theta.cos()]);
+    assert_matrix_close(&generator.expm(), &expected, 1e-13);
+}
+
+#[test]
+fn expm_of_a_times_expm_of_minus_a_is_identity() {
+    for seed in 0..5 {
+        let a = Matrix::from_fn(4, 4, |i, j| (((i * 5 + j * 3 + seed) % 7) as f64 - 3.0) / 4.0);
+        let product = a.expm() * (-&a).expm();
+        assert_matrix_close(&product, &Matrix::identity(4), 1e-10);
+    }
+}
+
+#[test]
+fn sqrtm_of_diagonal_matrix_is_elementwise_sqrt() {
+    let d = Matrix::from_diag(&[4.0, 9.0, 2.0]);
+    assert_eq!(d.sqrtm().unwrap(), Matrix::from_diag(&[2.0, 3.0, 2.0f64.sqrt()]));
+}
+
+#[test]
+fn sqrtm_squares_back_to_spd_matrix() {
+    for seed in 0..4 {
+        let b = well_conditioned(3 + seed as usize, seed);
+        let a = &b * b.transpose();
+        let root = a.sqrtm().unwrap();
+        assert_matrix_close(&(&root * &root), &a, 1e-9);
+        assert_matrix_close(&root, &root.transpose(), 1e-12);
+        assert!(root.cholesky().is_ok());
+    }
+}
+
+#[test]
+fn sqrtm_error_paths() {
+    let op = "take the square root of";
+    let indefinite = Matrix::new(2, 2, &[1.0, 2.0, 2.0, 1.0]);
+    assert_eq!(indefinite.sqrtm(), Err(MatrixError::NotPositiveDefinite));
+    let asymmetric = Matrix::new(2, 2, &[2.0, 1.0, 0.0, 2.0]);
+    assert_eq!(asymmetric.sqrtm(), Err(MatrixError::NotSymmetric { at: (0, 1), op }));
+    assert_eq!(Matrix::<f64>::zeros(1, 2).sqrtm(), Err(MatrixError::NotSquare { shape: (1, 2), op }));
+}
+
+fn eval_poly(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().fold(0.0, |value, &c| value * x + c)
+}
+
+#[test]
+fn charpoly_of_2x2_is_trace_and_det() {
+    let m = Matrix::new(2, 2, &[3.0, 1.0, -2.0, 5.0]);
+    assert_eq!(m.charpoly(), vec![1.0, -8.0, 17.0]);
+    assert_eq!(Matrix::<f64>::new(0, 0, &[]).charpoly(), vec![1.0]);
+}
+
+#[test]
+fn charpoly_of_known_3x3() {
+    // (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6
+    let m = Matrix::new(3, 3, &[1.0, 4.0, 5.0, 0.0, 2.0, 6.0, 0.0, 0.0, 3.0]);
+    let coefficients = m.charpoly();
+    for (actual, expected) in coefficients.iter().zip([1.0, -6.0, 11.0, -6.0].iter()) {
+        assert_close(*actual, *expected, 1e-12);
+    }
+}
+
+#[test]
+fn charpoly_vanishes_at_eigenvalues() {
+    let a = symmetric(4, 2);
+    let coefficients = a.charpoly();
+    assert_close(coefficients[1], -a.trace(), 1e-9);
+    assert_close(coefficients[4], a.det().unwrap(), 1e-9 * a.det().unwrap().abs());
+    let (values, _) = a.eig_sym().unwrap();
+    let scale = eval_poly(&coefficients.iter().map(|c| c.abs()).collect::<Vec<_>>(), values[3].abs());
+    for value in values {
+        assert!(eval_poly(&coefficients, value).abs() <= 1e-12 * scale);
+    }
+}
+
+#[test]
+fn null_space_of_rank_deficient_matrices() {
+    let matrices = vec![
+        Matrix::new(3, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+        Matrix::new(2, 4, &[1.0, 0.0, 2.0, -1.0, 0.0, 1.0, 1.0, 3.0]),
+        Matrix::new(3, 2, &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0]),
+        Matrix::zeros(2, 3),
+    ];
+    for a in matrices {
+        let n = a.null_space(1e-12);
+        let (_, col) = a.size();
+        assert_eq!(n.size(), (col, col - a.rank()));
+        assert_matrix_close(&(&a * &n), &Matrix::zeros(a.size().0, n.size().1), 1e-12);
+        assert_eq!(n.rank(), n.size().1);
+    }
+}
+
+#[test]
+fn null_space_of_known_matrix() {
+    let a = Matrix::new(2, 3, &[1.0, 2.0, 3.0, 2.0, 4.0, 6.0]);
+    let n = a.null_space(1e-12);
+    assert_eq!(n, Matrix::new(3, 2, &[-2.0, -3.0, 1.0, 0.0, 0.0, 1.0]));
+}
+
+#[test]
+fn null_space_of_full_rank_matrix_is_empty() {
+    let a = well_conditioned(4, 6);
+    let n = a.null_space(1e-12);
+    assert_eq!(n.size(), (4, 0));
+    assert_eq!((&a * &n).size(), (4, 0));
+    assert_eq!(n.transpose().size(), (0, 4));
+    assert_eq!(n.iter().count(), 0);
+    let coeffs = Matrix::new(0, 1, &[]);
+    assert_eq!(&n * &coeffs, Matrix::new(4, 1, &[0.0; 4]));
+}
+
+#[test]
+fn det_exact_of_known_integer_matrices() {
+    assert_eq!(Matrix::new(3, 3, &[2i64, -3, 1, 2, 0, -1, 1, 4, 5]).det_exact(), 49);
+    assert_eq!(Matrix::new(3, 3, &[1i64, 2, 3, 4, 5, 6, 7, 8, 9]).det_exact(), 0);
+    assert_eq!(Matrix::<i64>::identity(5).det_exact(), 1);
+    assert_eq!(Matrix::<i64>::new(0, 0, &[]).det_exact(), 1);
+}
+
+#[test]
+fn det_exact_where_float_determinant_rounds() {
+    let n = 1_000_000_000i64;
+    let m = Matrix::new(2, 2, &[n + 1, n, n, n - 1]);
+    assert_eq!(m.det_exact(), -1);
+    let big = Matrix::new(3, 3, &[1i128 << 25, 3, 5, 7, 1 << 25, 11, 13, 17, 1 << 25]);
+    let expected = (1i128 << 75) - (11 * 17 + 3 * 7 + 5 * 13) * (1 << 25) + 3 * 11 * 13 + 5 * 7 * 17;
+    assert_eq!(big.det_exact(), expected);
+}
+
+#[test]
+fn det_exact_sign_follows_row_swaps() {
+    assert_eq!(Matrix::new(2, 2, &[0i64, 1, 1, 0]).det_exact(), -1);
+    assert_eq!(Matrix::new(3, 3, &[0i64, 0, 1, 0, 1, 0, 1, 0, 0]).det_exact(), -1);
+    assert_eq!(Matrix::new(3, 3, &[0i64, 2, 0, 0, 0, 3, 4, 0, 0]).det_exact(), 24);
+    let m = Matrix::new(4, 4, &[0i64, 2, 1, 3, 1, 0, 2, 1, 3, 1, 0, 2, 2, 3, 1, 0]);
+    assert_eq!(m.det_exact(), -62);
+}
+
+#[test]
+fn solve_lower_triangular_known_system() {
+    let l = Matrix::new(3, 3, &[2.0, 0.0, 0.0, 1.0, 3.0, 0.0, -1.0, 2.0, 4.0]);
+    let x = l.solve_lower_triangular(&[4.0, 11.0, 16.0]).unwrap();
+    assert_eq!(x, vec![2.0, 3.0, 3.0]);
+}
+
+#[test]
+fn solve_upper_triangular_known_system() {
+    let u = Matrix::new(3, 3, &[2.0, 1.0, -1.0, 0.0, 3.0, 2.0, 0.0, 0.0, 4.0]);
+    let x = u.solve_upper_triangular(&[4.0, 15.0, 12.0]).unwrap();
+    assert_eq!(x, vec![2.0, 3.0, 3.0]);
+}
+
+#[test]
+fn triangular_solves_ignore_the_other_triangle() {
+    let full = Matrix::new(3, 3, &[2.0, 9.0, 9.0, 1.0, 3.0, 9.0, -1.0, 2.0, 4.0]);
+    assert_eq!(full.solve_lower_triangular(&[4.0, 11.0, 16.0]).unwrap(), vec![2.0, 3.0, 3.0]);
+    let full = Matrix::new(3, 3, &[2.0, 1.0, -1.0, 9.0, 3.0, 2.0, 9.0, 9.0, 4.0]);
+    assert_eq!(full.solve_upper_triangular(&[4.0, 15.0, 12.0]).unwrap(), vec![2.0, 3.0, 3.0]);
+}
+
+#[test]
+fn triangular_solves_with_cholesky_factor() {
+    let b = well_conditioned(6, 8);
+    let a = &b * b.transpose();
+    let l = a.cholesky().unwrap();
+    let rhs: Vec<f64> = (0..6).map(|i| (i as f64).sin()).collect();
+    let y = l.solve_lower_triangular(&rhs).unwrap();
+    let x = l.transpose().solve_upper_triangular(&y).unwrap();
+    assert!(residual_norm(&a, &x, &rhs) < 1e-9);
+}
+
+#[test]
+fn triangular_solve_error_paths() {
+    let zero_diagonal = Matrix::new(2, 2, &[1.0, 0.0, 5.0, 0.0]);
+    assert_eq!(zero_diagonal.solve_lower_triangular(&[1.0, 2.0]), Err(MatrixError::Singular));
+    assert_eq!(zero_diagonal.solve_upper_triangular(&[1.0, 2.0]), Err(MatrixError::Singular));
+    let id = Matrix::<f64>::identity(2);
+    assert_eq!(id.solve_upper_triangular(&[1.0]), Err(MatrixError::LengthMismatch { expected: 2, actual: 1 }));
+    assert_eq!(Matrix::<f64>::zeros(2, 3).solve_lower_triangular(&[1.0, 2.0]),
+               Err(MatrixError::NotSquare { shape: (2, 3), op: "solve a lower triangular system with" }));
+}
+
+#[test]
+fn dominant_eig_of_diagonal_matrix() {
+    let d = Matrix::from_diag(&[1.0, -5.0, 3.0, 2.0]);
+    let (value, vector) = d.dominant_eig(1000, 1e-10).unwrap();
+    assert_close(value, -5.0, 1e-8);
+    assert_close(vector[1].abs(), 1.0, 1e-8);
+}
+
+#[test]
+fn dominant_eig_of_symmetric_matrix_with_known_spectrum() {
+    // Eigenvalues 2 - sqrt(2), 2 and 2 + sqrt(2).
+    let m = Matrix::new(3, 3, &[2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]);
+    let (value, vector) = m.dominant_eig(1000, 1e-10).unwrap();
+    assert_close(value, 2.0 + 2.0f64.sqrt(), 1e-8);
+    let expected = [0.5, -0.5f64.sqrt(), 0.5];
+    let sign = vector[0].signum();
+    for (actual, expected) in vector.iter().zip(expected.iter()) {
+        assert_close(sign * actual, *expected, 1e-6);
+    }
+}
+
+#[test]
+fn dominant_eig_reports_non_convergence() {
+    let swap = Matrix::new(2, 2, &[0.0, 1.0, 1.0, 0.0]);
+    match swap.dominant_eig(50, 1e-10) {
+        Err(MatrixError::NoConvergence { iterations, residual, last }) => {
+            assert_eq!(iterations, 50);
+            assert!(residual > 1e-10);
+            assert_eq!(last.len(), 2);
+        }
+        other => panic!("expected NoConvergence, got {:?}", other),
+    }
+    assert_eq!(Matrix::<f64>::zeros(2, 3).dominant_eig(10, 1e-10),
+               Err(MatrixError::NotSquare { shape: (2, 3), op: "find the dominant eigenpair of" }));
+}
+
+/// The 5-point discrete Laplacian on a `k` x `k` grid, a sparse SPD matrix.
+fn laplacian_2d(k: usize) -> Matrix<f64> {
+    Matrix::from_fn(k * k, k * k, |i, j| {
+        let (ri, ci, rj, cj) = (i / k, i % k, j / k, j % k);
+        if i == j {
+            4.0
+        } else if (ri == rj && (ci as isize - cj as isize).abs() == 1)
+            || (ci == cj && (ri as isize - rj as isize).abs() == 1) {
+            -1.0
+        } else {
+            0.0
+        }
+    })
+}
+
+#[test]
+fn solve_cg_matches_direct_solver_on_laplacian() {
+    let a = laplacian_2d(8);
+    let b: Vec<f64> = (0..64).map(|i| ((i * 7) % 11) as f64 - 5.0).collect();
+    let x = a.solve_cg(&b, 1e-12, 200).unwrap();
+    let direct = a.solve(&b).unwrap();
+    for (cg, lu) in x.iter().zip(direct.iter()) {
+        assert_close(*cg, *lu, 1e-9);
+    }
+    assert!(residual_norm(&a, &x, &b) < 1e-9);
+}
+
+#[test]
+fn solve_cg_with_zero_right_hand_side() {
+    let a = laplacian_2d(3);
+    assert_eq!(a.solve_cg(&[0.0; 9], 1e-12, 10).unwrap(), vec![0.0; 9]);
+}
+
+#[test]
+fn solve_cg_error_paths() {
+    let a = laplacian_2d(8);
+    let b = vec![1.0; 64];
+    match a.solve_cg(&b, 1e-12, 3) {
+        Err(MatrixError::NoConvergence { iterations, residual, last }) => {
+            assert_eq!(iterations, 3);
+            assert!(residual > 1e-12 * 8.0);
+            assert_eq!(last.len(), 64);
+        }
+        other => panic!("expected NoConvergence, got {:?}", other),
+    }
+    let indefinite = Matrix::new(2, 2, &[1.0, 0.0, 0.0, -1.0]);
+    assert_eq!(indefinite.solve_cg(&[0.0, 1.0], 1e-12, 10), Err(MatrixError::NotPositiveDefinite));
+    assert_eq!(a.solve_cg(&[1.0], 1e-12, 10), Err(MatrixError::LengthMismatch { expected: 64, actual: 1 }));
+}
+
+#[test]
+fn jacobi_and_gauss_seidel_agree_with_direct_solver() {
+    let a = well_conditioned(6, 12);
+    let b: Vec<f64> = (0..6).map(|i| i as f64 * 1.5 - 4.0).collect();
+    let direct = a.solve(&b).unwrap();
+    let jacobi = a.solve_jacobi(&b, 1e-12, 1000).unwrap();
+    let gauss_seidel = a.solve_gauss_seidel(&b, 1e-12, 1000).unwrap();
+    for i in 0..6 {
+        assert_close(jacobi[i], direct[i], 1e-9);
+        assert_close(gauss_seidel[i], direct[i], 1e-9);
+    }
+}
+
+#[test]
+fn gauss_seidel_converges_faster_than_jacobi() {
+    let a = laplacian_2d(4) + Matrix::identity(16);
+    let b = vec![1.0; 16];
+    let final_residual = |result: Result<Vec<f64>, MatrixError>| match result {
+        Err(MatrixError::NoConvergence { residual, .. }) => residual,
+        other => panic!("expected NoConvergence, got {:?}", other),
+    };
+    let jacobi = final_residual(a.solve_jacobi(&b, 1e-300, 10));
+    let gauss_seidel = final_residual(a.solve_gauss_seidel(&b, 1e-300, 10));
+    assert!(gauss_seidel < jacobi);
+}
+
+#[test]
+fn iterative_solvers_report_non_convergence() {
+    let non_dominant = Matrix::new(2, 2, &[1.0, 3.0, 2.0, 1.0]);
+    for result in [non_dominant.solve_jacobi(&[1.0, 1.0], 1e-10, 100),
+                   non_dominant.solve_gauss_seidel(&[1.0, 1.0], 1e-10, 100)] {
+        match result {
+            Err(MatrixError::NoConvergence { iterations, residual, last }) => {
+                assert!(iterations <= 100);
+                assert!(residual > 1.0 || !residual.is_finite());
+                assert_eq!(last.len(), 2);
+            }
+            other => panic!("expected NoConvergence, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn iterative_solvers_reject_zero_diagonal() {
+    let m = Matrix::new(2, 2, &[0.0, 1.0, 1.0, 2.0]);
+    assert_eq!(m.solve_jacobi(&[1.0, 1.0], 1e-10, 10), Err(MatrixError::Singular));
+    assert_eq!(m.solve_gauss_seidel(&[1.0, 1.0], 1e-10, 10), Err(MatrixError::Singular));
+}
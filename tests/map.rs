@@ -0,0 +1,87 @@
+extern crate your;
+
+use your::{Matrix, MatrixError};
+
+#[test]
+fn map_preserves_shape() {
+    let m = Matrix::new(2, 3, &[1, -2, 3, -4, 5, -6]);
+    let squared = m.map(|&x| x * x);
+    assert_eq!(squared.size(), (2, 3));
+    assert_eq!(squared, Matrix::new(2, 3, &[1, 4, 9, 16, 25, 36]));
+    assert_eq!(Matrix::<i32>::zeros(0, 4).map(|&x| x + 1).size(), (0, 4));
+}
+
+#[test]
+fn map_changes_element_type() {
+    let m = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let floats: Matrix<f64> = m.map(|&x| f64::from(x) / 2.0);
+    assert_eq!(floats, Matrix::new(2, 2, &[0.5, 1.0, 1.5, 2.0]));
+    let signs = Matrix::new(1, 3, &[-1.5, 0.0, 2.5]).map(|&x: &f64| x > 0.0);
+    assert_eq!(signs, Matrix::new(1, 3, &[false, false, true]));
+}
+
+#[test]
+fn map_visits_elements_in_row_major_order() {
+    let m = Matrix::new(2, 2, &[10, 20, 30, 40]);
+    let mut seen = Vec::new();
+    let index = m.map(|&x| {
+        seen.push(x);
+        seen.len()
+    });
+    assert_eq!(seen, vec![10, 20, 30, 40]);
+    assert_eq!(index, Matrix::new(2, 2, &[1, 2, 3, 4]));
+}
+
+#[test]
+fn apply_doubles_every_element() {
+    let mut m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    m.apply(|x| *x *= 2);
+    assert_eq!(m, Matrix::new(2, 3, &[2, 4, 6, 8, 10, 12]));
+}
+
+#[test]
+fn apply_indexed_zeroes_strict_upper_triangle() {
+    let mut m = Matrix::new(3, 3, &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    m.apply_indexed(|i, j, x| if j > i { *x = 0 });
+    assert_eq!(m, Matrix::new(3, 3, &[1, 0, 0, 4, 5, 0, 7, 8, 9]));
+}
+
+#[test]
+fn apply_indexed_sees_every_position() {
+    let mut m = Matrix::<usize>::zeros(2, 3);
+    m.apply_indexed(|i, j, x| *x = i * 10 + j);
+    assert_eq!(m, Matrix::new(2, 3, &[0, 1, 2, 10, 11, 12]));
+}
+
+#[test]
+fn zip_with_element_wise_max() {
+    let a = Matrix::new(2, 2, &[1, 5, -3, 4]);
+    let b = Matrix::new(2, 2, &[2, 0, -4, 4]);
+    assert_eq!(a.zip_with(&b, |&x, &y| x.max(y)).unwrap(), Matrix::new(2, 2, &[2, 5, -3, 4]));
+}
+
+#[test]
+fn zip_with_and_map_mimic_component_div() {
+    let a = Matrix::new(2, 3, &[2.0, 9.0, -4.0, 1.0, 0.0, 8.0]);
+    let b = Matrix::new(2, 3, &[4.0, 3.0, 2.0, 1.0, 5.0, -2.0]);
+    let reciprocal = b.map(|&x| 1.0 / x);
+    let quotient = a.zip_with(&reciprocal, |x, y| x * y).unwrap();
+    assert_eq!(quotient, a.component_div(&b));
+}
+
+#[test]
+fn zip_with_different_element_types() {
+    let values = Matrix::new(1, 3, &[1.5, 2.5, 3.5]);
+    let mask = Matrix::new(1, 3, &[true, false, true]);
+    let masked = values.zip_with(&mask, |&x, &keep| if keep { x } else { 0.0 }).unwrap();
+    assert_eq!(masked, Matrix::new(1, 3, &[1.5, 0.0, 3.5]));
+}
+
+#[test]
+fn zip_with_shape_mismatch() {
+    let a = Matrix::<i32>::zeros(2, 3);
+    let b = Matrix::<i32>::zeros(3, 2);
+    let err = a.zip_with(&b, |x, y| x + y).unwrap_err();
+    assert_eq!(err, MatrixError::DimensionMismatch { left: (2, 3), right: (3, 2), op: "combine" });
+    assert_eq!(err.to_string(), "cannot combine 2x3 matrix and 3x2 matrix: dimensions differ");
+}
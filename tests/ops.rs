@@ -0,0 +1,204 @@
+extern crate your;
+
+use your::{Matrix, MatrixError};
+
+#[test]
+fn checked_add_and_sub() {
+    let a = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let b = Matrix::new(2, 2, &[10, 20, 30, 40]);
+    assert_eq!(a.checked_add(&b), Ok(Matrix::new(2, 2, &[11, 22, 33, 44])));
+    assert_eq!(b.checked_sub(&a), Ok(Matrix::new(2, 2, &[9, 18, 27, 36])));
+    assert_eq!(a.checked_add(&b).unwrap(), &a + &b);
+}
+
+#[test]
+fn checked_ops_report_dimension_mismatch() {
+    let a = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let b = Matrix::new(3, 2, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(a.checked_add(&b), Err(MatrixError::DimensionMismatch { left: (2, 3), right: (3, 2), op: "add" }));
+    assert_eq!(a.checked_sub(&b), Err(MatrixError::DimensionMismatch { left: (2, 3), right: (3, 2), op: "subtract" }));
+    assert_eq!(a.checked_mul(&a), Err(MatrixError::DimensionMismatch { left: (2, 3), right: (2, 3), op: "multiply" }));
+}
+
+#[test]
+fn checked_mul_matches_operator() {
+    let a = Matrix::new(2, 3, &[-2, -1, 0, 1, 2, 3]);
+    let b = Matrix::new(3, 2, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(a.checked_mul(&b), Ok(Matrix::new(2, 2, &[-5, -8, 22, 28])));
+    assert_eq!(a.checked_mul(&b).unwrap(), a * b);
+}
+
+#[test]
+fn mul_with_empty_inner_dimension_is_zero() {
+    let a: Matrix<i32> = Matrix::new(2, 0, &[]);
+    let b: Matrix<i32> = Matrix::new(0, 3, &[]);
+    assert_eq!(a.checked_mul(&b), Ok(Matrix::new(2, 3, &[0; 6])));
+    assert_eq!(&a * &b, Matrix::new(2, 3, &[0; 6]));
+    assert_eq!(a * b, Matrix::new(2, 3, &[0; 6]));
+    let c: Matrix<f64> = Matrix::new(0, 2, &[]);
+    let d: Matrix<f64> = Matrix::new(2, 0, &[]);
+    assert_eq!(&c * &d, Matrix::new(0, 0, &[]));
+    assert_eq!(&d * &c, Matrix::new(2, 2, &[0.0; 4]));
+}
+
+fn product_of(a: &Matrix<i32>, b: &Matrix<i32>, c: &Matrix<i32>) -> Result<Matrix<i32>, MatrixError> {
+    let ab = a.checked_mul(b)?;
+    ab.checked_mul(c)
+}
+
+#[test]
+fn checked_mul_works_with_question_mark() {
+    let a = Matrix::new(1, 2, &[1, 2]);
+    let b = Matrix::new(2, 2, &[1, 0, 0, 1]);
+    assert_eq!(product_of(&a, &b, &b), Ok(a.checked_mul(&b).unwrap()));
+    let err = product_of(&a, &a, &b).unwrap_err();
+    assert_eq!(err.to_string(), "cannot multiply 1x2 matrix by 1x2 matrix: inner dimensions differ");
+}
+
+#[test]
+#[should_panic(expected = "cannot add 2x3 matrix and 3x2 matrix: dimensions differ")]
+fn add_panics_with_shapes() {
+    let _ = Matrix::new(2, 3, &[0; 6]) + Matrix::new(3, 2, &[0; 6]);
+}
+
+#[test]
+#[should_panic(expected = "cannot add 1x2 matrix and 2x1 matrix: dimensions differ")]
+fn add_ref_panics_with_shapes() {
+    let _ = &Matrix::new(1, 2, &[0; 2]) + &Matrix::new(2, 1, &[0; 2]);
+}
+
+#[test]
+#[should_panic(expected = "cannot subtract 2x2 matrix from 2x3 matrix: dimensions differ")]
+fn sub_panics_with_shapes() {
+    let _ = &Matrix::new(2, 3, &[0; 6]) - Matrix::new(2, 2, &[0; 4]);
+}
+
+#[test]
+#[should_panic(expected = "cannot multiply 2x3 matrix by 4x2 matrix: inner dimensions differ")]
+fn mul_panics_with_shapes() {
+    let _ = Matrix::new(2, 3, &[0; 6]) * &Matrix::new(4, 2, &[0; 8]);
+}
+
+#[test]
+fn neg_negates_every_element() {
+    let m = Matrix::new(2, 3, &[1, -2, 3, 0, -5, 6]);
+    assert_eq!(-&m, Matrix::new(2, 3, &[-1, 2, -3, 0, 5, -6]));
+    assert_eq!(-(-&m), m);
+}
+
+#[test]
+fn neg_composes_with_add_and_sub() {
+    let a = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let b = Matrix::new(2, 2, &[10, 20, 30, 40]);
+    assert_eq!(-&a + &b, &b - &a);
+    assert_eq!(&b - -&a, &b + &a);
+    assert_eq!(&a + (-&a), Matrix::zeros(2, 2));
+}
+
+#[test]
+fn add_assign_matches_add() {
+    let a = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let b = Matrix::new(2, 3, &[6, 5, 4, 3, 2, 1]);
+    let mut c = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    c += &b;
+    assert_eq!(c, &a + &b);
+    c += Matrix::new(2, 3, &[1; 6]);
+    assert_eq!(c, Matrix::filled(2, 3, 8));
+}
+
+#[test]
+fn sub_assign_matches_sub() {
+    let a = Matrix::new(2, 2, &[1.5, 2.0, 3.0, 4.0]);
+    let b = Matrix::new(2, 2, &[0.5, 1.0, 1.0, 1.0]);
+    let mut c = Matrix::new(2, 2, &[1.5, 2.0, 3.0, 4.0]);
+    c -= &b;
+    assert_eq!(c, &a - &b);
+    c -= b;
+    assert_eq!(c, Matrix::new(2, 2, &[0.5, 0.0, 1.0, 2.0]));
+}
+
+#[test]
+#[should_panic(expected = "cannot subtract 1x2 matrix from 2x1 matrix: dimensions differ")]
+fn sub_assign_panics_with_shapes() {
+    let mut a = Matrix::new(2, 1, &[1, 2]);
+    a -= Matrix::new(1, 2, &[1, 2]);
+}
+
+#[test]
+fn mul_vec_matches_column_matrix_product() {
+    let a = Matrix::new(2, 3, &[-2, -1, 0, 1, 2, 3]);
+    let v = [4, 5, 6];
+    let expected = &a * Matrix::new(3, 1, &v);
+    assert_eq!(a.mul_vec(&v), expected.data().clone());
+    assert_eq!(a.mul_vec(&v), vec![-13, 32]);
+}
+
+#[test]
+fn vec_mul_matches_row_matrix_product() {
+    let a = Matrix::new(2, 3, &[-2, -1, 0, 1, 2, 3]);
+    let v = [3, -1];
+    let expected = Matrix::new(1, 2, &v) * &a;
+    assert_eq!(a.vec_mul(&v), expected.data().clone());
+    assert_eq!(a.vec_mul(&v), vec![-7, -5, -3]);
+}
+
+#[test]
+#[should_panic(expected = "cannot multiply 2x3 matrix by vector of length 2")]
+fn mul_vec_rejects_wrong_length() {
+    Matrix::new(2, 3, &[0; 6]).mul_vec(&[1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "cannot multiply vector of length 3 by 2x3 matrix")]
+fn vec_mul_rejects_wrong_length() {
+    Matrix::new(2, 3, &[0; 6]).vec_mul(&[1, 2, 3]);
+}
+
+#[test]
+fn vector_products_with_empty_inner_dimension_are_zero() {
+    let wide: Matrix<i32> = Matrix::new(3, 0, &[]);
+    assert_eq!(wide.mul_vec(&[]), vec![0, 0, 0]);
+    assert_eq!(wide.vec_mul(&[1, 2, 3]), Vec::<i32>::new());
+    let tall: Matrix<i32> = Matrix::new(0, 2, &[]);
+    assert_eq!(tall.vec_mul(&[]), vec![0, 0]);
+    assert_eq!(tall.mul_vec(&[1, 2]), Vec::<i32>::new());
+}
+
+#[test]
+fn pow_computes_fibonacci_numbers() {
+    let q: Matrix<u64> = Matrix::new(2, 2, &[1, 1, 1, 0]);
+    // [[1, 1], [1, 0]]^n == [[F(n+1), F(n)], [F(n), F(n-1)]]
+    assert_eq!(q.pow(1), q);
+    assert_eq!(q.pow(10), Matrix::new(2, 2, &[89, 55, 55, 34]));
+    assert_eq!(q.pow(90)[(0, 1)], 2_880_067_194_370_816_120);
+}
+
+#[test]
+fn pow_zero_is_identity() {
+    let m = Matrix::new(3, 3, &[2, 0, 1, 1, 3, 0, 0, 1, 4]);
+    assert_eq!(m.pow(0), Matrix::identity(3));
+}
+
+#[test]
+fn pow_of_empty_matrix_is_empty() {
+    let empty: Matrix<i32> = Matrix::new(0, 0, &[]);
+    for exp in 0..5 {
+        assert_eq!(empty.pow(exp), Matrix::identity(0));
+    }
+}
+
+#[test]
+fn pow_matches_repeated_multiplication() {
+    let m = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let mut expected = Matrix::identity(2);
+    for exp in 0..8 {
+        assert_eq!(m.pow(exp), expected);
+        expected = &expected * &m;
+    }
+}
+
+#[test]
+#[should_panic(expected = "cannot raise 2x3 matrix to a power: matrix is not square")]
+fn pow_rejects_non_square() {
+    Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]).pow(2);
+}
@@ -0,0 +1,135 @@
+extern crate your;
+
+use your::Matrix;
+
+#[test]
+fn is_square() {
+    assert!(Matrix::<i32>::zeros(3, 3).is_square());
+    assert!(!Matrix::<i32>::zeros(2, 3).is_square());
+    assert!(Matrix::<i32>::new(0, 0, &[]).is_square());
+    assert!(Matrix::new(1, 1, &[5]).is_square());
+}
+
+#[test]
+fn is_symmetric() {
+    assert!(Matrix::new(3, 3, &[1, 2, 3, 2, 4, 5, 3, 5, 6]).is_symmetric());
+    assert!(!Matrix::new(3, 3, &[1, 2, 3, 2, 4, 5, 3, 6, 6]).is_symmetric());
+    assert!(!Matrix::new(2, 3, &[1, 1, 1, 1, 1, 1]).is_symmetric());
+    assert!(Matrix::<i32>::new(0, 0, &[]).is_symmetric());
+    assert!(Matrix::new(1, 1, &[7]).is_symmetric());
+}
+
+#[test]
+fn is_symmetric_with_tolerance() {
+    let nearly = Matrix::new(2, 2, &[1.0, 2.0, 2.0 + 1e-12, 3.0]);
+    assert!(!nearly.is_symmetric());
+    assert!(nearly.is_symmetric_with_tol(1e-10));
+    assert!(!nearly.is_symmetric_with_tol(1e-14));
+    assert!(!Matrix::<f64>::zeros(1, 2).is_symmetric_with_tol(1.0));
+}
+
+#[test]
+fn is_diagonal() {
+    assert!(Matrix::from_diag(&[1, 0, 3]).is_diagonal());
+    assert!(Matrix::<i32>::zeros(3, 3).is_diagonal());
+    assert!(!Matrix::new(2, 2, &[1, 0, 1, 1]).is_diagonal());
+    assert!(!Matrix::<i32>::zeros(2, 3).is_diagonal());
+    assert!(Matrix::<i32>::new(0, 0, &[]).is_diagonal());
+    assert!(Matrix::new(1, 1, &[4]).is_diagonal());
+}
+
+#[test]
+fn is_identity() {
+    assert!(Matrix::<f64>::identity(4).is_identity());
+    assert!(!Matrix::from_diag(&[1, 2]).is_identity());
+    assert!(!Matrix::new(2, 2, &[1, 1, 0, 1]).is_identity());
+    assert!(!Matrix::new(2, 3, &[1, 0, 0, 0, 1, 0]).is_identity());
+    assert!(Matrix::<i32>::new(0, 0, &[]).is_identity());
+    assert!(Matrix::new(1, 1, &[1]).is_identity());
+    assert!(!Matrix::new(1, 1, &[0]).is_identity());
+}
+
+#[test]
+fn triangular_predicates() {
+    let upper = Matrix::new(3, 3, &[1, 2, 3, 0, 4, 5, 0, 0, 6]);
+    assert!(upper.is_upper_triangular());
+    assert!(!upper.is_lower_triangular());
+    let lower = upper.transpose();
+    assert!(lower.is_lower_triangular());
+    assert!(!lower.is_upper_triangular());
+    let full = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    assert!(!full.is_upper_triangular());
+    assert!(!full.is_lower_triangular());
+}
+
+#[test]
+fn diagonal_matrices_are_upper_and_lower_triangular() {
+    let diag = Matrix::from_diag(&[1, 0, 5]);
+    assert!(diag.is_upper_triangular());
+    assert!(diag.is_lower_triangular());
+    let empty = Matrix::<i32>::new(0, 0, &[]);
+    assert!(empty.is_upper_triangular() && empty.is_lower_triangular());
+}
+
+#[test]
+fn triangular_predicates_on_rectangular_matrices() {
+    let wide = Matrix::new(2, 4, &[1, 2, 3, 4, 0, 5, 6, 7]);
+    assert!(wide.is_upper_triangular());
+    assert!(!wide.is_lower_triangular());
+    let tall = Matrix::new(4, 2, &[1, 0, 2, 3, 4, 5, 6, 7]);
+    assert!(tall.is_lower_triangular());
+    assert!(!tall.is_upper_triangular());
+    let echelon = Matrix::new(3, 2, &[1, 2, 0, 3, 0, 0]);
+    assert!(echelon.is_upper_triangular());
+}
+
+#[test]
+fn is_orthogonal() {
+    for &theta in &[0.0f64, 0.3, 1.2, -2.5] {
+        let rotation = Matrix::new(2, 2, &[theta.cos(), -theta.sin(), theta.sin(), theta.cos()]);
+        assert!(rotation.is_orthogonal(1e-12));
+    }
+    let permutation = Matrix::new(3, 3, &[0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0]);
+    assert!(permutation.is_orthogonal(0.0));
+    assert!(!Matrix::new(2, 2, &[1.0, 1.0, 0.0, 1.0]).is_orthogonal(1e-12));
+    assert!(!Matrix::from_diag(&[2.0, 1.0]).is_orthogonal(1e-12));
+    assert!(!Matrix::new(2, 1, &[1.0, 0.0]).is_orthogonal(1e-12));
+    assert!(Matrix::<f64>::new(0, 0, &[]).is_orthogonal(0.0));
+}
+
+#[test]
+fn any_and_all_on_empty_matrix() {
+    let empty: Matrix<i32> = Matrix::new(0, 3, &[]);
+    assert!(!empty.any(|_| true));
+    assert!(empty.all(|_| false));
+    let empty: Matrix<f64> = Matrix::new(2, 0, &[]);
+    assert!(!empty.any_nan());
+    assert!(empty.all_finite());
+}
+
+#[test]
+fn any_and_all_short_circuit() {
+    let m = Matrix::new(2, 3, &[1i32, 2, -3, 4, -5, 6]);
+    let mut calls = 0;
+    assert!(m.any(|&value| { calls += 1; value < 0 }));
+    assert_eq!(calls, 3);
+    calls = 0;
+    assert!(!m.all(|&value| { calls += 1; value > 0 }));
+    assert_eq!(calls, 3);
+    calls = 0;
+    assert!(m.all(|&value| { calls += 1; value.abs() <= 6 }));
+    assert_eq!(calls, 6);
+}
+
+#[test]
+fn nan_and_finite_checks() {
+    let clean = Matrix::new(1, 3, &[1.0f64, -2.0, 0.0]);
+    assert!(!clean.any_nan());
+    assert!(clean.all_finite());
+    let infinite = Matrix::new(1, 2, &[1.0f64, f64::INFINITY]);
+    assert!(!infinite.any_nan());
+    assert!(!infinite.all_finite());
+    let nan = Matrix::new(1, 2, &[f32::NAN, 1.0]);
+    assert!(nan.any_nan());
+    assert!(!nan.all_finite());
+}
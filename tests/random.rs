@@ -0,0 +1,78 @@
+#![cfg(feature = "rand")]
+
+extern crate rand;
+extern crate your;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use your::Matrix;
+
+#[test]
+fn random_stays_in_range() {
+    let m = Matrix::random(10, 10, -5..5);
+    assert_eq!(m.size(), (10, 10));
+    assert!(m.iter().all(|v| (-5..5).contains(v)));
+    let f = Matrix::random(4, 25, 0.0..1.0);
+    assert!(f.iter().all(|v| (0.0..1.0).contains(v)));
+}
+
+#[test]
+fn seeded_random_is_reproducible() {
+    let a = Matrix::random_with(&mut StdRng::seed_from_u64(42), 3, 4, 0.0..10.0);
+    let b = Matrix::random_with(&mut StdRng::seed_from_u64(42), 3, 4, 0.0..10.0);
+    let c = Matrix::random_with(&mut StdRng::seed_from_u64(43), 3, 4, 0.0..10.0);
+    assert_eq!(a, b);
+    assert!(a != c);
+}
+
+#[test]
+fn random_covers_small_integer_range() {
+    let m = Matrix::random_with(&mut StdRng::seed_from_u64(7), 20, 20, 250u8..255);
+    for value in 250..255 {
+        assert!(m.iter().any(|&v| v == value));
+    }
+    assert!(m.iter().all(|v| (250..255).contains(v)));
+}
+
+#[test]
+fn random_full_width_signed_range() {
+    let m = Matrix::random_with(&mut StdRng::seed_from_u64(1), 8, 8, i64::MIN..i64::MAX);
+    assert!(m.iter().any(|&v| v < 0));
+    assert!(m.iter().any(|&v| v > 0));
+}
+
+#[test]
+#[should_panic(expected = "cannot sample from an empty range")]
+fn random_rejects_empty_range() {
+    Matrix::random(2, 2, 3..3);
+}
+
+#[test]
+fn random_normal_sample_moments() {
+    let m = Matrix::random_normal_with(&mut StdRng::seed_from_u64(2024), 200, 100, 3.0, 2.0);
+    let n = m.data().len() as f64;
+    let mean = m.iter().sum::<f64>() / n;
+    let variance = m.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1.0);
+    assert!((mean - 3.0).abs() < 0.1, "mean {}", mean);
+    assert!((variance - 4.0).abs() < 0.2, "variance {}", variance);
+}
+
+#[test]
+fn seeded_random_normal_is_reproducible() {
+    let a = Matrix::random_normal_with(&mut StdRng::seed_from_u64(5), 3, 3, 0.0f32, 1.0);
+    let b = Matrix::random_normal_with(&mut StdRng::seed_from_u64(5), 3, 3, 0.0f32, 1.0);
+    assert_eq!(a, b);
+    assert_eq!(a.size(), (3, 3));
+    assert!(a.iter().all(|v| v.is_finite()));
+    let unseeded = Matrix::random_normal(1, 3, 0.0, 1.0);
+    assert_eq!(unseeded.data().len(), 3);
+}
+
+#[test]
+fn constructors_accept_any_rand_rng() {
+    let mut thread = rand::rng();
+    assert_eq!(Matrix::random_with(&mut thread, 2, 3, 0u32..10).size(), (2, 3));
+    assert_eq!(Matrix::random_normal_with(&mut thread, 3, 2, 0.0, 1.0).size(), (3, 2));
+    let dynamic: &mut dyn rand::RngCore = &mut StdRng::seed_from_u64(9);
+    assert!(Matrix::random_with(dynamic, 1, 4, -1.0..1.0).iter().all(|v| (-1.0..1.0).contains(v)));
+}
@@ -0,0 +1,271 @@
+extern crate your;
+
+use your::Matrix;
+
+#[test]
+fn sum_and_product_of_known_matrix() {
+    let m = Matrix::new(2, 3, &[1, -2, 3, 4, -5, 6]);
+    assert_eq!(m.sum(), 7);
+    assert_eq!(m.product(), 720);
+    let floats = Matrix::new(2, 2, &[0.5, 1.5, 2.0, 4.0]);
+    assert_eq!(floats.sum(), 8.0);
+    assert_eq!(floats.product(), 6.0);
+}
+
+#[test]
+fn sum_and_product_of_empty_matrix() {
+    let empty = Matrix::<i32>::zeros(0, 3);
+    assert_eq!(empty.sum(), 0);
+    assert_eq!(empty.product(), 1);
+}
+
+#[test]
+fn iterator_sum_equals_chained_addition() {
+    let a = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let b = Matrix::new(2, 2, &[-1, 0, 5, 2]);
+    let c = Matrix::new(2, 2, &[10, 20, 30, 40]);
+    let expected = &a + &b + &c;
+    let by_ref: Matrix<i32> = vec![&a, &b, &c].into_iter().sum();
+    assert_eq!(by_ref, expected);
+    let owned: Matrix<i32> = vec![a, b, c].into_iter().sum();
+    assert_eq!(owned, expected);
+}
+
+#[test]
+fn iterator_sum_of_one_matrix() {
+    let a = Matrix::new(1, 2, &[3, 4]);
+    assert_eq!(vec![a].into_iter().sum::<Matrix<i32>>(), Matrix::new(1, 2, &[3, 4]));
+}
+
+#[test]
+#[should_panic(expected = "cannot sum an empty iterator of matrices")]
+fn iterator_sum_of_nothing_panics() {
+    let _: Matrix<i32> = Vec::<Matrix<i32>>::new().into_iter().sum();
+}
+
+#[test]
+#[should_panic(expected = "cannot add 1x2 matrix and 2x1 matrix: dimensions differ")]
+fn iterator_sum_of_mismatched_shapes_panics() {
+    let _: Matrix<i32> = vec![Matrix::zeros(1, 2), Matrix::zeros(2, 1)].into_iter().sum();
+}
+
+#[test]
+fn row_and_col_sums_of_rectangular_matrix() {
+    let m = Matrix::new(2, 3, &[1, -2, 3, -4, 5, -6]);
+    assert_eq!(m.row_sums(), vec![2, -5]);
+    assert_eq!(m.col_sums(), vec![-3, 3, -3]);
+    let t = m.transpose();
+    assert_eq!(t.row_sums(), m.col_sums());
+    assert_eq!(t.col_sums(), m.row_sums());
+}
+
+#[test]
+fn axis_sums_agree_with_total() {
+    let m = Matrix::from_fn(4, 5, |i, j| (i as i64 - 2) * (j as i64 + 1));
+    assert_eq!(m.row_sums().iter().sum::<i64>(), m.sum());
+    assert_eq!(m.col_sums().iter().sum::<i64>(), m.sum());
+}
+
+#[test]
+fn axis_sums_of_empty_shapes() {
+    assert_eq!(Matrix::<i32>::zeros(0, 3).col_sums(), vec![0, 0, 0]);
+    assert_eq!(Matrix::<i32>::zeros(0, 3).row_sums(), Vec::<i32>::new());
+    assert_eq!(Matrix::<i32>::zeros(2, 0).row_sums(), vec![0, 0]);
+}
+
+#[test]
+fn min_max_with_negatives() {
+    let m = Matrix::new(2, 3, &[3, -7, 2, 9, 0, -1]);
+    assert_eq!(m.min(), Some(-7));
+    assert_eq!(m.max(), Some(9));
+    assert_eq!(m.argmin(), Some((0, 1)));
+    assert_eq!(m.argmax(), Some((1, 0)));
+}
+
+#[test]
+fn ties_resolve_to_first_occurrence() {
+    let m = Matrix::new(3, 2, &[1, 5, 0, 5, 0, 1]);
+    assert_eq!(m.argmax(), Some((0, 1)));
+    assert_eq!(m.argmin(), Some((1, 0)));
+    let constant = Matrix::filled(2, 2, 4);
+    assert_eq!(constant.argmin(), Some((0, 0)));
+    assert_eq!(constant.argmax(), Some((0, 0)));
+}
+
+#[test]
+fn min_max_of_1x1_and_empty() {
+    let single = Matrix::new(1, 1, &[-3.5]);
+    assert_eq!(single.min(), Some(-3.5));
+    assert_eq!(single.argmax(), Some((0, 0)));
+    let empty = Matrix::<i32>::zeros(0, 4);
+    assert_eq!(empty.min(), None);
+    assert_eq!(empty.argmax(), None);
+}
+
+#[test]
+fn nan_elements_are_skipped() {
+    let m = Matrix::new(2, 2, &[f64::NAN, 2.0, -1.0, f64::NAN]);
+    assert_eq!(m.max(), Some(2.0));
+    assert_eq!(m.argmin(), Some((1, 0)));
+    assert_eq!(Matrix::filled(1, 2, f64::NAN).max(), None);
+}
+
+#[test]
+fn row_and_col_argmax_with_ties() {
+    let m = Matrix::new(3, 4, &[1, 7, 7, 0,
+                                5, 2, 5, 5,
+                                -1, -3, 9, -3]);
+    assert_eq!(m.row_argmax(), vec![1, 0, 2]);
+    assert_eq!(m.row_argmin(), vec![3, 1, 1]);
+    assert_eq!(m.col_argmax(), vec![1, 0, 2, 1]);
+    assert_eq!(m.col_argmin(), vec![2, 2, 1, 2]);
+}
+
+#[test]
+fn col_argmax_matches_row_argmax_of_transpose() {
+    let m = Matrix::from_fn(5, 3, |i, j| ((i * 7 + j * 5) % 4) as i32);
+    assert_eq!(m.col_argmax(), m.transpose().row_argmax());
+    assert_eq!(m.col_argmin(), m.transpose().row_argmin());
+}
+
+#[test]
+fn axis_argmax_skips_nan() {
+    let m = Matrix::new(2, 3, &[f64::NAN, 1.0, 0.5, f64::NAN, f64::NAN, f64::NAN]);
+    assert_eq!(m.row_argmax(), vec![1, 0]);
+    assert_eq!(m.col_argmin(), vec![0, 0, 0]);
+}
+
+#[test]
+fn axis_argmax_of_empty_shapes() {
+    assert_eq!(Matrix::<i32>::zeros(0, 3).row_argmax(), Vec::<usize>::new());
+    assert_eq!(Matrix::<i32>::zeros(3, 0).col_argmax(), Vec::<usize>::new());
+}
+
+#[test]
+#[should_panic(expected = "cannot take the row-wise argmax of 2x0 matrix: rows are empty")]
+fn row_argmax_of_zero_width_rows_panics() {
+    Matrix::<i32>::zeros(2, 0).row_argmax();
+}
+
+#[test]
+#[should_panic(expected = "cannot take the column-wise argmin of 0x2 matrix: columns are empty")]
+fn col_argmin_of_zero_height_columns_panics() {
+    Matrix::<i32>::zeros(0, 2).col_argmin();
+}
+
+#[test]
+fn cumsum_of_known_matrix() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, -5, 6]);
+    assert_eq!(m.cumsum_rows(), Matrix::new(2, 3, &[1, 3, 6, 4, -1, 5]));
+    assert_eq!(m.cumsum_cols(), Matrix::new(2, 3, &[1, 2, 3, 5, -3, 9]));
+}
+
+#[test]
+fn last_column_of_cumsum_rows_is_row_sums() {
+    let m = Matrix::from_fn(4, 5, |i, j| (i as i64 * 3 - j as i64) * (j as i64 % 3 - 1));
+    assert_eq!(m.cumsum_rows().column(4), m.row_sums());
+    assert_eq!(m.cumsum_cols().row(3).to_vec(), m.col_sums());
+}
+
+#[test]
+fn summed_area_table_answers_rectangle_sums() {
+    let m = Matrix::from_fn(5, 6, |i, j| ((i * 7 + j * 11) % 9) as i64 - 4);
+    let table = m.cumsum_rows().cumsum_cols();
+    assert_eq!(table, m.cumsum_cols().cumsum_rows());
+    let at = |i: usize, j: usize| if i == 0 || j == 0 { 0 } else { table[(i - 1, j - 1)] };
+    for top in 0..5 {
+        for bottom in (top + 1)..=5 {
+            for left in 0..6 {
+                for right in (left + 1)..=6 {
+                    let mut brute = 0;
+                    for i in top..bottom {
+                        for j in left..right {
+                            brute += m[(i, j)];
+                        }
+                    }
+                    let fast = at(bottom, right) - at(top, right) - at(bottom, left) + at(top, left);
+                    assert_eq!(fast, brute);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn cumsum_of_empty_shapes() {
+    assert_eq!(Matrix::<i32>::zeros(0, 3).cumsum_cols().size(), (0, 3));
+    assert_eq!(Matrix::<i32>::zeros(3, 0).cumsum_rows().size(), (3, 0));
+}
+
+#[test]
+fn diff_of_known_matrix() {
+    let m = Matrix::new(2, 4, &[1, 4, 9, 16, 2, 0, -2, 5]);
+    assert_eq!(m.diff_rows(), Matrix::new(2, 3, &[3, 5, 7, -2, -2, 7]));
+    assert_eq!(m.diff_cols(), Matrix::new(1, 4, &[1, -4, -11, -11]));
+}
+
+#[test]
+fn diff_inverts_cumsum() {
+    let m = Matrix::from_fn(4, 5, |i, j| (i as i32 - 2) * (j as i32 * 3 - 5));
+    let rows = m.cumsum_rows().diff_rows();
+    assert_eq!(rows, Matrix::from_fn(4, 4, |i, j| m[(i, j + 1)]));
+    let cols = m.cumsum_cols().diff_cols();
+    assert_eq!(cols, Matrix::from_fn(3, 5, |i, j| m[(i + 1, j)]));
+}
+
+#[test]
+fn diff_of_single_row_or_column_is_empty() {
+    let column = Matrix::new(3, 1, &[1, 2, 3]);
+    assert_eq!(column.diff_rows().size(), (3, 0));
+    assert_eq!(column.diff_cols(), Matrix::new(2, 1, &[1, 1]));
+    let row = Matrix::new(1, 3, &[1, 2, 3]);
+    assert_eq!(row.diff_cols().size(), (0, 3));
+    assert_eq!(row.diff_cols().transpose().size(), (3, 0));
+    assert_eq!(Matrix::<i32>::zeros(0, 0).diff_rows().size(), (0, 0));
+    assert_eq!(Matrix::<i32>::zeros(0, 0).diff_cols().size(), (0, 0));
+}
+
+#[test]
+fn count_nonzero_with_explicit_zeros_and_negatives() {
+    let m = Matrix::new(3, 3, &[0, -1, 0, 2, 0, -3, 0, 0, 4]);
+    assert_eq!(m.count_nonzero(), 4);
+    assert_eq!(Matrix::new(1, 4, &[0.0, -0.0, f64::NAN, 1e-300]).count_nonzero(), 2);
+    assert_eq!(Matrix::<i32>::zeros(2, 2).count_nonzero(), 0);
+}
+
+#[test]
+fn count_where_overall_and_per_axis() {
+    let m = Matrix::new(3, 3, &[0, -1, 0, 2, 0, -3, 0, 0, 4]);
+    assert_eq!(m.count_where(|&value| value < 0), 2);
+    assert_eq!(m.count_where(|&value| value > 10), 0);
+    assert_eq!(m.row_counts_where(|&value| value != 0), vec![1, 2, 1]);
+    assert_eq!(m.col_counts_where(|&value| value != 0), vec![1, 1, 2]);
+    assert_eq!(m.col_counts_where(|&value| value >= 0), vec![3, 2, 2]);
+}
+
+#[test]
+fn counts_on_empty_matrices() {
+    let empty: Matrix<i32> = Matrix::new(0, 3, &[]);
+    assert_eq!(empty.count_nonzero(), 0);
+    assert_eq!(empty.count_where(|_| true), 0);
+    assert_eq!(empty.row_counts_where(|_| true), Vec::<usize>::new());
+    assert_eq!(empty.col_counts_where(|_| true), vec![0, 0, 0]);
+    let no_cols: Matrix<i32> = Matrix::new(2, 0, &[]);
+    assert_eq!(no_cols.row_counts_where(|_| true), vec![0, 0]);
+}
+
+#[test]
+fn bincount_of_labels() {
+    let labels = Matrix::new(2, 4, &[0u8, 2, 2, 5, 1, 0, 2, 5]);
+    let counts = labels.bincount();
+    assert_eq!(counts, vec![2, 1, 3, 0, 0, 2]);
+    assert_eq!(counts.iter().sum::<usize>(), labels.iter().count());
+    assert_eq!(Matrix::new(1, 2, &[3i64, 3]).bincount(), vec![0, 0, 0, 2]);
+    assert!(Matrix::<u32>::new(0, 2, &[]).bincount().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "cannot bincount element -1 at (1, 0): not a non-negative integer")]
+fn bincount_rejects_negative_values() {
+    Matrix::new(2, 2, &[0, 1, -1, 2]).bincount();
+}
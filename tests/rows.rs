@@ -0,0 +1,236 @@
+extern crate your;
+
+use your::{Matrix, MatrixError};
+
+fn sample() -> Matrix<i32> {
+    Matrix::new(3, 4, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12])
+}
+
+#[test]
+fn swap_rows_leaves_other_rows_untouched() {
+    let mut m = sample();
+    m.swap_rows(0, 2);
+    assert_eq!(m, Matrix::new(3, 4, &[9, 10, 11, 12, 5, 6, 7, 8, 1, 2, 3, 4]));
+    m.swap_rows(2, 0);
+    assert_eq!(m, sample());
+    m.swap_rows(1, 1);
+    assert_eq!(m, sample());
+}
+
+#[test]
+fn swap_cols_leaves_other_cols_untouched() {
+    let mut m = sample();
+    m.swap_cols(0, 3);
+    assert_eq!(m, Matrix::new(3, 4, &[4, 2, 3, 1, 8, 6, 7, 5, 12, 10, 11, 9]));
+    m.swap_cols(1, 2);
+    assert_eq!(m, Matrix::new(3, 4, &[4, 3, 2, 1, 8, 7, 6, 5, 12, 11, 10, 9]));
+    m.swap_cols(3, 3);
+    assert_eq!(m[(2, 3)], 9);
+}
+
+#[test]
+#[should_panic(expected = "row index 3 out of bounds for 3x4 matrix")]
+fn swap_rows_out_of_bounds() {
+    sample().swap_rows(0, 3);
+}
+
+#[test]
+#[should_panic(expected = "column index 4 out of bounds for 3x4 matrix")]
+fn swap_cols_out_of_bounds() {
+    sample().swap_cols(4, 4);
+}
+
+#[test]
+fn scale_and_add_rows_and_cols() {
+    let mut m = sample();
+    m.scale_row(1, -1);
+    assert_eq!(m, Matrix::new(3, 4, &[1, 2, 3, 4, -5, -6, -7, -8, 9, 10, 11, 12]));
+    m.add_scaled_row(0, 2, 2);
+    assert_eq!(m, Matrix::new(3, 4, &[1, 2, 3, 4, -5, -6, -7, -8, 11, 14, 17, 20]));
+    m.add_scaled_row(0, 0, 1);
+    assert_eq!(m.row(0), &[2, 4, 6, 8]);
+
+    let mut m = sample();
+    m.scale_col(2, 10);
+    assert_eq!(m, Matrix::new(3, 4, &[1, 2, 30, 4, 5, 6, 70, 8, 9, 10, 110, 12]));
+    m.add_scaled_col(0, 3, -1);
+    assert_eq!(m, Matrix::new(3, 4, &[1, 2, 30, 3, 5, 6, 70, 3, 9, 10, 110, 3]));
+}
+
+#[test]
+fn manual_elimination_matches_rref() {
+    let a = Matrix::new(3, 4, &[2.0, 1.0, -1.0, 8.0, -3.0, -1.0, 2.0, -11.0, -2.0, 1.0, 2.0, -3.0]);
+    let mut m = a.map(|&value| value);
+    m.scale_row(0, 0.5);
+    m.add_scaled_row(0, 1, 3.0);
+    m.add_scaled_row(0, 2, 2.0);
+    m.scale_row(1, 2.0);
+    m.add_scaled_row(1, 0, -0.5);
+    m.add_scaled_row(1, 2, -2.0);
+    m.scale_row(2, -1.0);
+    m.add_scaled_row(2, 0, 1.0);
+    m.add_scaled_row(2, 1, -1.0);
+    assert_eq!(m, Matrix::new(3, 4, &[1.0, 0.0, 0.0, 2.0, 0.0, 1.0, 0.0, 3.0, 0.0, 0.0, 1.0, -1.0]));
+    let rref = a.rref();
+    for (x, y) in m.iter().zip(rref.iter()) {
+        assert!((x - y).abs() < 1e-12);
+    }
+}
+
+#[test]
+#[should_panic(expected = "row index 5 out of bounds for 3x4 matrix")]
+fn add_scaled_row_out_of_bounds() {
+    sample().add_scaled_row(5, 0, 1);
+}
+
+#[test]
+#[should_panic(expected = "column index 4 out of bounds for 3x4 matrix")]
+fn scale_col_out_of_bounds() {
+    sample().scale_col(4, 2);
+}
+
+#[test]
+fn insert_row_shifts_later_rows() {
+    let mut m = sample();
+    m.insert_row(1, &[-1, -2, -3, -4]).unwrap();
+    assert_eq!(m, Matrix::new(4, 4, &[1, 2, 3, 4, -1, -2, -3, -4, 5, 6, 7, 8, 9, 10, 11, 12]));
+    m.insert_row(0, &[0; 4]).unwrap();
+    assert_eq!(m.row(0), &[0; 4]);
+    m.insert_row(5, &[7; 4]).unwrap();
+    assert_eq!(m.size(), (6, 4));
+    assert_eq!(m.row(5), &[7; 4]);
+    assert_eq!(m.row(4), &[9, 10, 11, 12]);
+}
+
+#[test]
+fn insert_col_relayouts_every_element() {
+    let mut m = sample();
+    m.insert_col(2, &[-1, -2, -3]).unwrap();
+    assert_eq!(m, Matrix::new(3, 5, &[1, 2, -1, 3, 4, 5, 6, -2, 7, 8, 9, 10, -3, 11, 12]));
+    for i in 0..3 {
+        for j in 0..5 {
+            let expected = match j {
+                2 => -(i as i32 + 1),
+                _ => (i * 4 + if j < 2 { j } else { j - 1 }) as i32 + 1,
+            };
+            assert_eq!(m[(i, j)], expected);
+        }
+    }
+
+    let mut design = sample();
+    design.insert_col(0, &[1; 3]).unwrap();
+    assert_eq!(design, Matrix::new(3, 5, &[1, 1, 2, 3, 4, 1, 5, 6, 7, 8, 1, 9, 10, 11, 12]));
+    let mut augmented = sample();
+    augmented.insert_col(4, &[0, 0, 1]).unwrap();
+    assert_eq!(augmented, Matrix::new(3, 5, &[1, 2, 3, 4, 0, 5, 6, 7, 8, 0, 9, 10, 11, 12, 1]));
+}
+
+#[test]
+fn insert_into_empty_matrix_takes_its_shape_from_values() {
+    let mut m: Matrix<i32> = Matrix::new(0, 0, &[]);
+    m.insert_row(0, &[1, 2, 3]).unwrap();
+    assert_eq!(m, Matrix::new(1, 3, &[1, 2, 3]));
+    let mut m: Matrix<i32> = Matrix::new(0, 0, &[]);
+    m.insert_col(0, &[1, 2]).unwrap();
+    assert_eq!(m, Matrix::new(2, 1, &[1, 2]));
+}
+
+#[test]
+fn insert_rejects_bad_positions_and_lengths() {
+    let mut m = sample();
+    let err = m.insert_row(4, &[0; 4]).unwrap_err();
+    assert_eq!(err, MatrixError::IndexOutOfBounds { index: 4, shape: (3, 4), op: "insert a row" });
+    assert_eq!(err.to_string(), "cannot insert a row at index 4 of 3x4 matrix: index out of bounds");
+    assert_eq!(m.insert_row(0, &[0; 3]), Err(MatrixError::LengthMismatch { expected: 4, actual: 3 }));
+    assert_eq!(m.insert_col(5, &[0; 3]).unwrap_err(),
+               MatrixError::IndexOutOfBounds { index: 5, shape: (3, 4), op: "insert a column" });
+    assert_eq!(m.insert_col(0, &[0; 4]), Err(MatrixError::LengthMismatch { expected: 3, actual: 4 }));
+    assert_eq!(m, sample());
+
+    let mut no_rows = Matrix::new(0, 3, &[]);
+    assert_eq!(no_rows.insert_row(0, &[1, 2]), Err(MatrixError::LengthMismatch { expected: 3, actual: 2 }));
+    assert_eq!(no_rows.size(), (0, 3));
+    let (_, mut emptied) = sample().split_at_row(3);
+    assert_eq!(emptied.insert_row(0, &[1, 2, 3]), Err(MatrixError::LengthMismatch { expected: 4, actual: 3 }));
+    let mut no_cols = Matrix::new(2, 0, &[]);
+    assert_eq!(no_cols.insert_col(0, &[1, 2, 3]), Err(MatrixError::LengthMismatch { expected: 2, actual: 3 }));
+    no_cols.insert_col(0, &[1, 2]).unwrap();
+    assert_eq!(no_cols, Matrix::new(2, 1, &[1, 2]));
+}
+
+#[test]
+fn remove_row_returns_its_elements() {
+    let mut m = sample();
+    assert_eq!(m.remove_row(1), vec![5, 6, 7, 8]);
+    assert_eq!(m, Matrix::new(2, 4, &[1, 2, 3, 4, 9, 10, 11, 12]));
+    assert_eq!(m.remove_row(1), vec![9, 10, 11, 12]);
+    assert_eq!(m.remove_row(0), vec![1, 2, 3, 4]);
+    assert_eq!(m.size(), (0, 4));
+    assert_eq!(m, Matrix::new(0, 4, &[]));
+}
+
+#[test]
+fn remove_col_returns_its_elements() {
+    let mut m = sample();
+    assert_eq!(m.remove_col(2), vec![3, 7, 11]);
+    assert_eq!(m, Matrix::new(3, 3, &[1, 2, 4, 5, 6, 8, 9, 10, 12]));
+    assert_eq!(m.remove_col(0), vec![1, 5, 9]);
+    assert_eq!(m, Matrix::new(3, 2, &[2, 4, 6, 8, 10, 12]));
+    m.remove_col(1);
+    assert_eq!(m.remove_col(0), vec![2, 6, 10]);
+    assert_eq!(m.size(), (3, 0));
+    assert_eq!(m, Matrix::new(3, 0, &[]));
+}
+
+#[test]
+#[should_panic(expected = "column index 0 out of bounds for 3x0 matrix")]
+fn remove_col_from_matrix_without_columns() {
+    let mut m: Matrix<i32> = Matrix::new(3, 0, &[]);
+    m.remove_col(0);
+}
+
+#[test]
+#[should_panic(expected = "row index 3 out of bounds for 3x4 matrix")]
+fn remove_row_out_of_bounds() {
+    sample().remove_row(3);
+}
+
+#[test]
+fn push_row_builds_matrix_incrementally() {
+    let mut m = Matrix::new(0, 0, &[]);
+    for record in &[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]] {
+        m.push_row(record).unwrap();
+    }
+    assert_eq!(m, Matrix::new(4, 3, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]));
+    assert_eq!(m.push_row(&[1, 2]), Err(MatrixError::LengthMismatch { expected: 3, actual: 2 }));
+    assert_eq!(m.size(), (4, 3));
+}
+
+#[test]
+fn push_col_builds_matrix_incrementally() {
+    let mut m = Matrix::new(0, 0, &[]);
+    for column in &[[1, 5, 9], [2, 6, 10], [3, 7, 11], [4, 8, 12]] {
+        m.push_col(column).unwrap();
+    }
+    assert_eq!(m, sample());
+    assert_eq!(m.push_col(&[0; 4]), Err(MatrixError::LengthMismatch { expected: 3, actual: 4 }));
+    assert_eq!(m, sample());
+
+    let mut by_rows = Matrix::new(0, 0, &[]);
+    for column in &[[1, 5, 9], [2, 6, 10], [3, 7, 11], [4, 8, 12]] {
+        by_rows.push_row(column).unwrap();
+    }
+    assert_eq!(by_rows.transpose(), sample());
+}
+
+#[test]
+fn push_into_matrix_without_rows_or_cols_checks_length() {
+    let mut no_rows = Matrix::new(0, 3, &[]);
+    assert_eq!(no_rows.push_row(&[1, 2]), Err(MatrixError::LengthMismatch { expected: 3, actual: 2 }));
+    assert_eq!(no_rows, Matrix::new(0, 3, &[]));
+    no_rows.push_row(&[1, 2, 3]).unwrap();
+    assert_eq!(no_rows, Matrix::new(1, 3, &[1, 2, 3]));
+    let mut no_cols = Matrix::new(2, 0, &[]);
+    assert_eq!(no_cols.push_col(&[1]), Err(MatrixError::LengthMismatch { expected: 2, actual: 1 }));
+    assert_eq!(no_cols.size(), (2, 0));
+}
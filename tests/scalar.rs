@@ -0,0 +1,94 @@
+extern crate your;
+
+use your::Matrix;
+
+#[test]
+fn scalar_mul_matches_repeated_add() {
+    let m = Matrix::new(2, 3, &[1, -2, 3, -4, 5, -6]);
+    assert_eq!(&m * 2, &m + &m);
+    assert_eq!(m.scale(3), &(&m + &m) + &m);
+    let scaled = m * 10;
+    assert_eq!(scaled.size(), (2, 3));
+    assert_eq!(scaled, Matrix::new(2, 3, &[10, -20, 30, -40, 50, -60]));
+}
+
+#[test]
+fn scalar_mul_floats() {
+    let m = Matrix::new(1, 3, &[0.5, 1.0, -2.0]);
+    assert_eq!(&m * 4.0, Matrix::new(1, 3, &[2.0, 4.0, -8.0]));
+    assert_eq!(m * 0.5, Matrix::new(1, 3, &[0.25, 0.5, -1.0]));
+}
+
+#[test]
+fn left_scalar_mul_i32() {
+    let m = Matrix::new(2, 2, &[1, -2, 3, -4]);
+    assert_eq!(2 * &m, &m * 2);
+    assert_eq!(-3 * m, Matrix::new(2, 2, &[-3, 6, -9, 12]));
+}
+
+#[test]
+fn left_scalar_mul_f64() {
+    let m = Matrix::new(1, 3, &[0.5, 1.0, -2.0]);
+    assert_eq!(2.5 * &m, &m * 2.5);
+    assert_eq!(0.5 * m, Matrix::new(1, 3, &[0.25, 0.5, -1.0]));
+}
+
+#[test]
+fn left_scalar_mul_other_primitives() {
+    assert_eq!(3u8 * Matrix::new(1, 2, &[2u8, 4]), Matrix::new(1, 2, &[6, 12]));
+    assert_eq!(2usize * &Matrix::new(1, 1, &[21usize]), Matrix::new(1, 1, &[42]));
+    assert_eq!(0.5f32 * Matrix::new(1, 1, &[3.0f32]), Matrix::new(1, 1, &[1.5]));
+}
+
+#[test]
+fn scalar_div_divides_every_element() {
+    let m = Matrix::new(2, 2, &[2, 4, -6, 9]);
+    assert_eq!(&m / 2, Matrix::new(2, 2, &[1, 2, -3, 4]));
+    let f = Matrix::new(1, 3, &[1.0, 2.0, 3.0]);
+    assert_eq!(f / 4.0, Matrix::new(1, 3, &[0.25, 0.5, 0.75]));
+}
+
+#[test]
+fn div_assign_in_place() {
+    let mut m = Matrix::new(1, 3, &[3.0, 6.0, 9.0]);
+    m /= 3.0;
+    assert_eq!(m, Matrix::new(1, 3, &[1.0, 2.0, 3.0]));
+}
+
+#[test]
+fn float_div_by_zero_propagates() {
+    let m = Matrix::new(1, 3, &[1.0, -1.0, 0.0]) / 0.0;
+    assert_eq!(m[(0, 0)], f64::INFINITY);
+    assert_eq!(m[(0, 1)], f64::NEG_INFINITY);
+    assert!(m[(0, 2)].is_nan());
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide matrix by zero")]
+fn integer_div_by_zero_panics() {
+    let _ = &Matrix::new(1, 2, &[1, 2]) / 0;
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide matrix by zero")]
+fn integer_div_by_zero_panics_on_empty_matrix() {
+    let mut m: Matrix<u32> = Matrix::new(0, 3, &[]);
+    m /= 0;
+}
+
+#[test]
+fn scalar_add_and_sub_round_trip() {
+    let m = Matrix::new(2, 3, &[1, -2, 3, -4, 5, -6]);
+    assert_eq!(&m + 5, Matrix::new(2, 3, &[6, 3, 8, 1, 10, -1]));
+    assert_eq!(&m - 1, Matrix::new(2, 3, &[0, -3, 2, -5, 4, -7]));
+    let shifted = (&m + 5) - 5;
+    assert_eq!(shifted.size(), (2, 3));
+    assert_eq!(shifted, m);
+}
+
+#[test]
+fn scalar_sub_centers_data() {
+    let m = Matrix::new(1, 4, &[1.0, 2.0, 3.0, 6.0]);
+    let mean = m.iter().sum::<f64>() / 4.0;
+    assert_eq!(m - mean, Matrix::new(1, 4, &[-2.0, -1.0, 0.0, 3.0]));
+}
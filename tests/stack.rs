@@ -0,0 +1,160 @@
+extern crate your;
+
+use your::{Matrix, MatrixError};
+
+#[test]
+fn hstack_then_submatrix_recovers_the_originals() {
+    let a = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let b = Matrix::new(2, 3, &[5, 6, 7, 8, 9, 10]);
+    let ab = a.hstack(&b).unwrap();
+    assert_eq!(ab, Matrix::new(2, 5, &[1, 2, 5, 6, 7, 3, 4, 8, 9, 10]));
+    assert_eq!(ab.submatrix(0..2, 0..2), a);
+    assert_eq!(ab.submatrix(0..2, 2..5), b);
+}
+
+#[test]
+fn hstack_forms_augmented_identity() {
+    let a = Matrix::new(2, 2, &[2.0, 1.0, 1.0, 1.0]);
+    let augmented = a.hstack(&Matrix::identity(2)).unwrap();
+    let inverse = augmented.rref().submatrix(0..2, 2..4);
+    assert_eq!(inverse, Matrix::new(2, 2, &[1.0, -1.0, -1.0, 2.0]));
+}
+
+#[test]
+fn vstack_concatenates_rows() {
+    let a = Matrix::new(1, 3, &[1, 2, 3]);
+    let b = Matrix::new(2, 3, &[4, 5, 6, 7, 8, 9]);
+    let ab = a.vstack(&b).unwrap();
+    assert_eq!(ab, Matrix::new(3, 3, &[1, 2, 3, 4, 5, 6, 7, 8, 9]));
+    assert_eq!(ab.submatrix(1..3, 0..3), b);
+}
+
+#[test]
+fn stacking_with_empty_matrices_is_identity() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let no_rows = Matrix::new(0, 3, &[]);
+    assert_eq!(m.vstack(&no_rows).unwrap(), m);
+    assert_eq!(no_rows.vstack(&m).unwrap(), m);
+    let no_cols = Matrix::new(2, 0, &[]);
+    assert_eq!(m.hstack(&no_cols).unwrap(), m);
+    assert_eq!(no_cols.hstack(&m).unwrap(), m);
+}
+
+#[test]
+fn stacking_mismatched_shapes_names_both() {
+    let a = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let b = Matrix::new(3, 1, &[5, 6, 7]);
+    let err = a.hstack(&b).unwrap_err();
+    assert_eq!(err, MatrixError::DimensionMismatch { left: (2, 2), right: (3, 1), op: "stack horizontally" });
+    assert_eq!(err.to_string(), "cannot stack 2x2 matrix and 3x1 matrix horizontally: row counts differ");
+    let err = a.vstack(&b).unwrap_err();
+    assert_eq!(err.to_string(), "cannot stack 2x2 matrix and 3x1 matrix vertically: column counts differ");
+}
+
+#[test]
+fn block_diag_of_two_square_blocks() {
+    let a = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let b = Matrix::new(2, 2, &[5, 6, 7, 8]);
+    assert_eq!(Matrix::block_diag(&[&a, &b]), Matrix::new(4, 4, &[
+        1, 2, 0, 0,
+        3, 4, 0, 0,
+        0, 0, 5, 6,
+        0, 0, 7, 8,
+    ]));
+}
+
+#[test]
+fn block_diag_of_rectangular_blocks() {
+    let a = Matrix::new(1, 2, &[1, 2]);
+    let b = Matrix::new(2, 1, &[3, 4]);
+    let empty = Matrix::new(0, 2, &[]);
+    let m = Matrix::block_diag(&[&a, &empty, &b]);
+    assert_eq!(m, Matrix::new(3, 5, &[
+        1, 2, 0, 0, 0,
+        0, 0, 0, 0, 3,
+        0, 0, 0, 0, 4,
+    ]));
+}
+
+#[test]
+fn block_diag_degenerate_cases() {
+    let a = Matrix::new(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(Matrix::block_diag(&[&a]), a);
+    let none: &[&Matrix<f64>] = &[];
+    assert_eq!(Matrix::block_diag(none), Matrix::new(0, 0, &[]));
+}
+
+#[test]
+fn from_blocks_assembles_mixed_sizes() {
+    let a = Matrix::new(2, 1, &[1, 2]);
+    let b = Matrix::new(2, 3, &[3, 4, 5, 6, 7, 8]);
+    let c = Matrix::new(1, 1, &[9]);
+    let d = Matrix::new(1, 3, &[10, 11, 12]);
+    let m = Matrix::from_blocks(&[&[&a, &b], &[&c, &d]]).unwrap();
+    assert_eq!(m, Matrix::new(3, 4, &[1, 3, 4, 5, 2, 6, 7, 8, 9, 10, 11, 12]));
+    assert_eq!(m.submatrix(0..2, 0..1), a);
+    assert_eq!(m.submatrix(0..2, 1..4), b);
+    assert_eq!(m.submatrix(2..3, 0..1), c);
+    assert_eq!(m.submatrix(2..3, 1..4), d);
+}
+
+#[test]
+fn from_blocks_builds_augmented_system() {
+    let a = Matrix::new(2, 2, &[4, 1, 1, 3]);
+    let b = Matrix::new(2, 1, &[1, 2]);
+    let bt = b.transpose();
+    let zero = Matrix::zeros(1, 1);
+    let kkt = Matrix::from_blocks(&[&[&a, &b], &[&bt, &zero]]).unwrap();
+    assert_eq!(kkt, Matrix::new(3, 3, &[4, 1, 1, 1, 3, 2, 1, 2, 0]));
+    assert_eq!(Matrix::<i32>::from_blocks(&[]).unwrap(), Matrix::new(0, 0, &[]));
+}
+
+#[test]
+fn from_blocks_identifies_the_bad_block() {
+    let a = Matrix::new(2, 2, &[1, 2, 3, 4]);
+    let tall = Matrix::new(3, 2, &[0; 6]);
+    let wide = Matrix::new(2, 3, &[0; 6]);
+    let err = Matrix::from_blocks(&[&[&a, &tall]]).unwrap_err();
+    assert_eq!(err, MatrixError::BlockShape { block: (0, 1), shape: (3, 2), expected: (2, 2) });
+    assert_eq!(err.to_string(), "block (0, 1) is 3x2, but its block row and column need 2x2");
+    let err = Matrix::from_blocks(&[&[&a, &a], &[&a, &wide]]).unwrap_err();
+    assert_eq!(err, MatrixError::BlockShape { block: (1, 1), shape: (2, 3), expected: (2, 2) });
+    let err = Matrix::from_blocks(&[&[&a, &a], &[&a]]).unwrap_err();
+    assert_eq!(err, MatrixError::RaggedRows { row: 1, expected: 2, actual: 1 });
+}
+
+#[test]
+fn split_at_row_round_trips_through_vstack() {
+    let m = Matrix::new(4, 2, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    let (train, validation) = m.split_at_row(3);
+    assert_eq!(train, Matrix::new(3, 2, &[1, 2, 3, 4, 5, 6]));
+    assert_eq!(validation, Matrix::new(1, 2, &[7, 8]));
+    for i in 0..5 {
+        let (top, bottom) = m.split_at_row(i);
+        assert_eq!(top.size(), (i, 2));
+        assert_eq!(top.vstack(&bottom).unwrap(), m);
+    }
+    let (empty, full) = m.split_at_row(0);
+    assert_eq!((empty, full), (Matrix::new(0, 2, &[]), Matrix::new(4, 2, &[1, 2, 3, 4, 5, 6, 7, 8])));
+}
+
+#[test]
+fn split_at_col_separates_augmented_matrix() {
+    let augmented = Matrix::new(2, 3, &[2, 1, 5, 1, 3, 10]);
+    let (a, b) = augmented.split_at_col(2);
+    assert_eq!(a, Matrix::new(2, 2, &[2, 1, 1, 3]));
+    assert_eq!(b, Matrix::new(2, 1, &[5, 10]));
+    for j in 0..4 {
+        let (left, right) = augmented.split_at_col(j);
+        assert_eq!(left.hstack(&right).unwrap(), augmented);
+    }
+    let (full, empty) = augmented.split_at_col(3);
+    assert_eq!(empty, Matrix::new(2, 0, &[]));
+    assert_eq!(full, a.hstack(&b).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "cannot split 2x3 matrix at column 4: index out of bounds")]
+fn split_past_the_end_panics() {
+    Matrix::new(2, 3, &[0; 6]).split_at_col(4);
+}
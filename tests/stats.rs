@@ -0,0 +1,210 @@
+extern crate your;
+
+use your::Matrix;
+
+fn assert_close(actual: f64, expected: f64, tol: f64) {
+    assert!((actual - expected).abs() <= tol, "expected {}, got {}", expected, actual);
+}
+
+fn assert_all_close(actual: &[f64], expected: &[f64], tol: f64) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected.iter()) {
+        assert_close(*a, *e, tol);
+    }
+}
+
+#[test]
+fn means_of_known_matrix() {
+    let m = Matrix::new(2, 3, &[1.0, 2.0, 3.0, 4.0, 6.0, 8.0]);
+    assert_close(m.mean(), 4.0, 1e-15);
+    assert_all_close(&m.row_means(), &[2.0, 6.0], 1e-15);
+    assert_all_close(&m.col_means(), &[2.5, 4.0, 5.5], 1e-15);
+}
+
+#[test]
+fn population_and_sample_variance() {
+    let m = Matrix::new(4, 2, &[2.0, 1.0, 4.0, 1.0, 4.0, 2.0, 6.0, 4.0]);
+    assert_all_close(&m.col_variances(0), &[2.0, 1.5], 1e-14);
+    assert_all_close(&m.col_variances(1), &[8.0 / 3.0, 2.0], 1e-14);
+    assert_all_close(&m.col_std_devs(0), &[2.0f64.sqrt(), 1.5f64.sqrt()], 1e-14);
+    assert_all_close(&m.row_variances(0), &[0.25, 2.25, 1.0, 1.0], 1e-14);
+    assert_all_close(&m.row_std_devs(1), &[0.5f64.sqrt(), 4.5f64.sqrt(), 2.0f64.sqrt(), 2.0f64.sqrt()], 1e-14);
+    // Elements: 1, 1, 2, 2, 4, 4, 4, 6 with mean 3 and squared deviations summing to 22.
+    assert_close(m.variance(0), 2.75, 1e-14);
+    assert_close(m.variance(1), 22.0 / 7.0, 1e-14);
+    assert_close(m.std_dev(0), 2.75f64.sqrt(), 1e-14);
+}
+
+#[test]
+fn constant_column_has_exactly_zero_variance() {
+    let m = Matrix::from_fn(7, 2, |i, j| if j == 0 { 0.1 } else { i as f64 });
+    assert_eq!(m.col_variances(0)[0], 0.0);
+    assert_eq!(m.col_variances(1)[0], 0.0);
+    assert_eq!(m.col_means()[0], 0.1);
+    assert_eq!(Matrix::filled(3, 3, 1e10 + 0.3).variance(1), 0.0);
+}
+
+#[test]
+fn stable_with_large_offset() {
+    let m = Matrix::new(1, 4, &[1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]);
+    assert_close(m.variance(1), 30.0, 1e-6);
+}
+
+#[test]
+fn degenerate_shapes_give_nan() {
+    assert!(Matrix::<f64>::zeros(0, 0).mean().is_nan());
+    assert!(Matrix::new(1, 1, &[3.0]).variance(1).is_nan());
+    assert_eq!(Matrix::new(1, 1, &[3.0]).variance(0), 0.0);
+    assert!(Matrix::<f64>::zeros(0, 2).col_means().iter().all(|m| m.is_nan()));
+}
+
+#[test]
+fn median_of_odd_and_even_columns() {
+    let odd = Matrix::new(5, 2, &[3.0, 10.0, 1.0, -2.0, 5.0, 4.0, 2.0, 8.0, 4.0, 0.0]);
+    assert_eq!(odd.col_median(), vec![3.0, 4.0]);
+    let even = Matrix::new(4, 2, &[3.0, 1.0, 1.0, 1.0, 7.0, 2.0, 2.0, 10.0]);
+    assert_eq!(even.col_median(), vec![2.5, 1.5]);
+}
+
+#[test]
+fn percentile_endpoints_match_min_median_max() {
+    let m = Matrix::from_fn(7, 3, |i, j| (((i * 5 + j * 3) % 7) as f64 - 3.0) * (j as f64 + 1.0));
+    let columns: Vec<Vec<f64>> = (0..3).map(|j| m.column(j)).collect();
+    let min: Vec<f64> = columns.iter().map(|c| c.iter().cloned().fold(f64::INFINITY, f64::min)).collect();
+    let max: Vec<f64> = columns.iter().map(|c| c.iter().cloned().fold(f64::NEG_INFINITY, f64::max)).collect();
+    assert_eq!(m.col_percentile(0.0), min);
+    assert_eq!(m.col_percentile(50.0), m.col_median());
+    assert_eq!(m.col_percentile(100.0), max);
+}
+
+#[test]
+fn percentile_interpolates_between_ranks() {
+    let m = Matrix::new(5, 1, &[10.0, 40.0, 20.0, 50.0, 30.0]);
+    assert_eq!(m.col_percentile(25.0), vec![20.0]);
+    assert_eq!(m.col_percentile(10.0), vec![14.0]);
+    assert_eq!(m.col_percentile(90.0), vec![46.0]);
+}
+
+#[test]
+fn percentile_with_nan_and_empty_columns() {
+    let m = Matrix::new(3, 2, &[1.0, f64::NAN, 2.0, 5.0, 3.0, 6.0]);
+    let median = m.col_median();
+    assert_eq!(median[0], 2.0);
+    assert!(median[1].is_nan());
+    assert!(Matrix::<f64>::zeros(0, 2).col_median().iter().all(|m| m.is_nan()));
+    assert_eq!(Matrix::<f64>::zeros(3, 0).col_median(), Vec::<f64>::new());
+}
+
+#[test]
+#[should_panic(expected = "percentile 101 is outside 0..=100")]
+fn percentile_out_of_range_panics() {
+    Matrix::<f64>::zeros(2, 2).col_percentile(101.0);
+}
+
+#[test]
+fn normalized_rows_have_unit_norm() {
+    let m = Matrix::new(3, 3, &[3.0, 4.0, 0.0, 0.0, 0.0, 0.0, -1.0, 2.0, -2.0]);
+    let normalized = m.normalize_rows();
+    assert_eq!(normalized.row(0), &[0.6, 0.8, 0.0][..]);
+    assert_eq!(normalized.row(1), &[0.0, 0.0, 0.0][..]);
+    for i in [0, 2] {
+        let norm = normalized.row(i).iter().map(|x| x * x).sum::<f64>().sqrt();
+        assert_close(norm, 1.0, 1e-15);
+    }
+    assert_close(normalized[(2, 1)], 2.0 / 3.0, 1e-15);
+}
+
+#[test]
+fn standardized_columns_have_zero_mean_and_unit_std() {
+    let m = Matrix::from_fn(6, 3, |i, j| (i as f64).powi(j as i32 + 1) - 2.0 * j as f64);
+    let (standardized, means, scales) = m.standardize_cols();
+    assert_all_close(&standardized.col_means(), &[0.0; 3], 1e-14);
+    assert_all_close(&standardized.col_std_devs(0), &[1.0; 3], 1e-14);
+    assert_all_close(&means, &m.col_means(), 1e-14);
+    assert_all_close(&scales, &m.col_std_devs(0), 1e-14);
+    let reapplied = Matrix::from_fn(6, 3, |i, j| (m[(i, j)] - means[j]) / scales[j]);
+    assert_eq!(reapplied, standardized);
+}
+
+#[test]
+fn standardizing_constant_column_gives_zeros() {
+    let m = Matrix::new(3, 2, &[5.0, 1.0, 5.0, 2.0, 5.0, 3.0]);
+    let (standardized, means, scales) = m.standardize_cols();
+    assert_eq!(standardized.column(0), vec![0.0, 0.0, 0.0]);
+    assert_eq!(means[0], 5.0);
+    assert_eq!(scales[0], 1.0);
+    assert!(standardized.iter().all(|x| x.is_finite()));
+}
+
+#[test]
+fn softmax_rows_sum_to_one() {
+    let m = Matrix::new(2, 3, &[1.0, 2.0, 3.0, -1.0, 0.0, 4.5]);
+    let softmax = m.softmax_rows();
+    for total in softmax.row_sums() {
+        assert_close(total, 1.0, 1e-15);
+    }
+    let e = [1.0f64.exp(), 2.0f64.exp(), 3.0f64.exp()];
+    let sum: f64 = e.iter().sum();
+    assert_all_close(softmax.row(0), &[e[0] / sum, e[1] / sum, e[2] / sum], 1e-15);
+}
+
+#[test]
+fn softmax_of_large_inputs_does_not_overflow() {
+    let m = Matrix::new(1, 3, &[1000.0, 1000.0, 999.0]);
+    let softmax = m.softmax_rows();
+    assert!(softmax.iter().all(|x| x.is_finite()));
+    assert_close(softmax[(0, 0)], softmax[(0, 1)], 0.0);
+    assert_close(softmax[(0, 0)] / softmax[(0, 2)], 1.0f64.exp(), 1e-12);
+    assert_close(softmax.sum(), 1.0, 1e-15);
+}
+
+#[test]
+fn softmax_splits_mass_over_positive_infinities() {
+    let m = Matrix::new(2, 4, &[
+        f64::INFINITY, 0.0, f64::INFINITY, 1e300,
+        f64::NEG_INFINITY, 2.0, f64::INFINITY, f64::NEG_INFINITY,
+    ]);
+    let softmax = m.softmax_rows();
+    assert_eq!(softmax.row(0), &[0.5, 0.0, 0.5, 0.0]);
+    assert_eq!(softmax.row(1), &[0.0, 0.0, 1.0, 0.0]);
+}
+
+#[test]
+fn softmax_of_negative_infinities_is_uniform() {
+    let m = Matrix::new(2, 4, &[
+        f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY,
+        f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY, 0.0,
+    ]);
+    let softmax = m.softmax_rows();
+    assert_eq!(softmax.row(0), &[0.25, 0.25, 0.25, 0.25]);
+    assert_eq!(softmax.row(1), &[0.0, 0.5, 0.0, 0.5]);
+}
+
+#[test]
+fn softmax_of_single_column_is_all_ones() {
+    let m = Matrix::new(3, 1, &[-5.0, 0.0, 1e300]);
+    assert_eq!(m.softmax_rows(), Matrix::ones(3, 1));
+}
+
+#[test]
+fn histogram_counts_every_in_range_element() {
+    let m = Matrix::new(2, 4, &[0.0, 0.1, 0.25, 0.5, 0.6, 0.74, 0.75, 1.0]);
+    let counts = m.histogram(4, (0.0, 1.0));
+    assert_eq!(counts, vec![2, 1, 3, 2]);
+    assert_eq!(counts.iter().sum::<usize>(), m.iter().count());
+    assert_eq!(m.histogram(1, (0.0, 1.0)), vec![8]);
+}
+
+#[test]
+fn histogram_drops_out_of_range_and_nan() {
+    let m = Matrix::new(1, 6, &[-0.5, 0.0, 2.0, 3.99, 4.0, f64::NAN]);
+    let counts = m.histogram(2, (0.0, 4.0));
+    assert_eq!(counts, vec![1, 3]);
+    assert_eq!(counts.iter().sum::<usize>(), m.iter().count() - 2);
+}
+
+#[test]
+#[should_panic(expected = "histogram range (1, 1) is not a finite interval with lo < hi")]
+fn histogram_rejects_empty_range() {
+    Matrix::new(1, 1, &[1.0]).histogram(3, (1.0, 1.0));
+}
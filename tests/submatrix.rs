@@ -0,0 +1,41 @@
+extern crate your;
+
+use your::Matrix;
+
+fn grid() -> Matrix<i32> {
+    Matrix::from_fn(4, 5, |i, j| (10 * i + j) as i32)
+}
+
+#[test]
+fn submatrix_of_interior_block() {
+    let m = grid();
+    assert_eq!(m.submatrix(1..3, 2..5), Matrix::new(2, 3, &[12, 13, 14, 22, 23, 24]));
+    assert_eq!(m.submatrix(3..4, 0..1), Matrix::new(1, 1, &[30]));
+}
+
+#[test]
+fn leading_principal_minors() {
+    let a = Matrix::new(3, 3, &[2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0]);
+    let minors: Vec<f64> = (1..4).map(|k| a.submatrix(0..k, 0..k).det().unwrap().round()).collect();
+    assert_eq!(minors, vec![2.0, 3.0, 4.0]);
+}
+
+#[test]
+fn full_range_equals_clone_and_empty_ranges_are_empty() {
+    let m = grid();
+    assert_eq!(m.submatrix(0..4, 0..5), m.clone());
+    assert_eq!(m.submatrix(2..2, 0..5), Matrix::new(0, 5, &[]));
+    assert_eq!(m.submatrix(0..4, 5..5), Matrix::new(4, 0, &[]));
+}
+
+#[test]
+#[should_panic(expected = "row range 2..5 out of bounds for 4x5 matrix")]
+fn submatrix_rows_out_of_bounds() {
+    grid().submatrix(2..5, 0..1);
+}
+
+#[test]
+#[should_panic(expected = "column range 4..6 out of bounds for 4x5 matrix")]
+fn submatrix_cols_out_of_bounds() {
+    grid().submatrix(0..1, 4..6);
+}
@@ -0,0 +1,59 @@
+extern crate your;
+
+use your::Matrix;
+
+#[test]
+fn transpose_rectangular() {
+    let m = Matrix::new(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let t = m.transpose();
+    assert_eq!(t.size(), (3, 2));
+    assert_eq!(t, Matrix::new(3, 2, &[1, 4, 2, 5, 3, 6]));
+    assert_eq!(t.transpose(), m);
+}
+
+#[test]
+fn transpose_vectors() {
+    let row = Matrix::new(1, 4, &[1, 2, 3, 4]);
+    assert_eq!(row.transpose(), Matrix::new(4, 1, &[1, 2, 3, 4]));
+    let column = Matrix::new(3, 1, &[5, 6, 7]);
+    assert_eq!(column.transpose(), Matrix::new(1, 3, &[5, 6, 7]));
+}
+
+#[test]
+fn transpose_of_product_reverses_factors() {
+    let a = Matrix::new(2, 3, &[-2, -1, 0, 1, 2, 3]);
+    let b = Matrix::new(3, 4, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+    assert_eq!((&a * &b).transpose(), b.transpose() * a.transpose());
+}
+
+#[test]
+fn transpose_in_place_matches_transpose() {
+    for n in 1..6 {
+        let values: Vec<usize> = (0..n * n).collect();
+        let mut m = Matrix::new(n, n, &values);
+        let expected = m.transpose();
+        m.transpose_in_place();
+        assert_eq!(m, expected);
+    }
+}
+
+#[test]
+fn transpose_in_place_one_by_one() {
+    let mut m = Matrix::new(1, 1, &[42]);
+    m.transpose_in_place();
+    assert_eq!(m, Matrix::new(1, 1, &[42]));
+}
+
+#[test]
+fn transpose_in_place_rectangular_matches_transpose() {
+    let shapes = [(3, 7), (7, 3), (1, 5), (5, 1), (2, 3), (4, 6), (5, 8), (13, 11), (0, 4)];
+    for &(row, col) in shapes.iter() {
+        let values: Vec<usize> = (0..row * col).collect();
+        let mut m = Matrix::new(row, col, &values);
+        let expected = m.transpose();
+        m.transpose_in_place();
+        assert_eq!(m, expected, "shape {}x{}", row, col);
+        m.transpose_in_place();
+        assert_eq!(m, Matrix::new(row, col, &values), "shape {}x{}", row, col);
+    }
+}
@@ -0,0 +1,45 @@
+extern crate your;
+
+use your::Matrix;
+
+fn sample() -> Matrix<i32> {
+    Matrix::new(3, 4, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12])
+}
+
+#[test]
+fn triu_and_tril_with_offsets() {
+    let m = sample();
+    assert_eq!(m.triu(0), Matrix::new(3, 4, &[1, 2, 3, 4, 0, 6, 7, 8, 0, 0, 11, 12]));
+    assert_eq!(m.triu(1), Matrix::new(3, 4, &[0, 2, 3, 4, 0, 0, 7, 8, 0, 0, 0, 12]));
+    assert_eq!(m.triu(-1), Matrix::new(3, 4, &[1, 2, 3, 4, 5, 6, 7, 8, 0, 10, 11, 12]));
+    assert_eq!(m.tril(0), Matrix::new(3, 4, &[1, 0, 0, 0, 5, 6, 0, 0, 9, 10, 11, 0]));
+    assert_eq!(m.tril(-1), Matrix::new(3, 4, &[0, 0, 0, 0, 5, 0, 0, 0, 9, 10, 0, 0]));
+    assert_eq!(m.tril(2), Matrix::new(3, 4, &[1, 2, 3, 0, 5, 6, 7, 8, 9, 10, 11, 12]));
+}
+
+#[test]
+fn triu_plus_strict_tril_is_the_matrix() {
+    let m = sample();
+    assert_eq!(m.triu(0) + m.tril(-1), m);
+    let t = m.transpose();
+    assert_eq!(t.triu(1) + t.tril(0), t);
+}
+
+#[test]
+fn offsets_beyond_the_matrix() {
+    let m = sample();
+    assert_eq!(m.triu(4), Matrix::zeros(3, 4));
+    assert_eq!(m.triu(-3), m);
+    assert_eq!(m.tril(-3), Matrix::zeros(3, 4));
+    assert_eq!(m.tril(3), m);
+    assert_eq!(m.triu(isize::MAX), Matrix::zeros(3, 4));
+    assert_eq!(m.tril(isize::MIN + 1), Matrix::zeros(3, 4));
+}
+
+#[test]
+fn triu_and_tril_results_are_triangular() {
+    let m = sample();
+    assert!(m.triu(0).is_upper_triangular());
+    assert!(m.tril(0).is_lower_triangular());
+    assert_eq!(Matrix::<i32>::new(0, 0, &[]).triu(0).size(), (0, 0));
+}
@@ -0,0 +1,103 @@
+extern crate your;
+
+use your::Matrix;
+
+fn grid() -> Matrix<i32> {
+    Matrix::from_fn(4, 5, |i, j| (10 * i + j) as i32)
+}
+
+#[test]
+fn view_reads_interior_block() {
+    let m = grid();
+    let v = m.view(1..3, 2..5);
+    assert_eq!(v.size(), (2, 3));
+    assert_eq!(v[(0, 0)], 12);
+    assert_eq!(v[(1, 2)], 24);
+    assert_eq!(v.get(1, 1), Some(&23));
+    assert_eq!(v.get(2, 0), None);
+    assert_eq!(v.row(1), &[22, 23, 24]);
+    let rows: Vec<&[i32]> = v.rows().collect();
+    assert_eq!(rows, vec![&[12, 13, 14][..], &[22, 23, 24][..]]);
+    assert_eq!(v.rows().next_back(), Some(&[22, 23, 24][..]));
+    assert_eq!(v.rows().len(), 2);
+}
+
+#[test]
+fn to_matrix_matches_submatrix() {
+    let m = grid();
+    let ranges = [(0..4, 0..5), (1..3, 2..5), (3..4, 0..1), (0..4, 4..5), (2..2, 1..3), (0..3, 3..3)];
+    for (rows, cols) in ranges.iter().cloned() {
+        assert_eq!(m.view(rows.clone(), cols.clone()).to_matrix(), m.submatrix(rows, cols));
+    }
+}
+
+#[test]
+fn empty_view_has_no_rows_to_read() {
+    let m = grid();
+    let v = m.view(4..4, 0..5);
+    assert_eq!(v.size(), (0, 5));
+    assert_eq!(v.rows().next(), None);
+    assert_eq!(m.view(0..2, 5..5).rows().collect::<Vec<_>>(), vec![&[][..], &[][..]]);
+}
+
+#[test]
+#[should_panic(expected = "index (2, 0) out of bounds for 2x3 view")]
+fn view_index_out_of_bounds() {
+    let m = grid();
+    let _ = m.view(1..3, 2..5)[(2, 0)];
+}
+
+#[test]
+#[should_panic(expected = "column range 3..6 out of bounds for 4x5 matrix")]
+fn view_range_out_of_bounds() {
+    grid().view(0..1, 3..6);
+}
+
+#[test]
+fn copy_into_corner_leaves_surroundings_untouched() {
+    let mut m = grid();
+    let patch = Matrix::new(2, 2, &[-1, -2, -3, -4]);
+    m.view_mut(0..2, 3..5).copy_from(&patch).unwrap();
+    assert_eq!(m.submatrix(0..2, 3..5), patch);
+    for (i, j, &value) in m.indexed_iter() {
+        if i >= 2 || j < 3 {
+            assert_eq!(value, (10 * i + j) as i32);
+        }
+    }
+}
+
+#[test]
+fn mutable_view_updates_block_in_place() {
+    let mut m = grid();
+    {
+        let mut block = m.view_mut(1..3, 1..3);
+        block.apply(|value| *value *= 100);
+        block[(0, 1)] = 7;
+        assert_eq!(block.size(), (2, 2));
+        assert_eq!(block.to_matrix(), Matrix::new(2, 2, &[1100, 7, 2100, 2200]));
+        assert_eq!(block.as_view().row(1), &[2100, 2200]);
+    }
+    assert_eq!(m.row(1), &[10, 1100, 7, 13, 14]);
+    assert_eq!(m.row(2), &[20, 2100, 2200, 23, 24]);
+    assert_eq!(m.row(3), &[30, 31, 32, 33, 34]);
+
+    m.view_mut(3..4, 0..5).fill(0);
+    assert_eq!(m.row(3), &[0; 5]);
+    m.view_mut(0..4, 2..2).fill(9);
+    assert_eq!(m.count_where(|&value| value == 9), 0);
+}
+
+#[test]
+fn copy_from_rejects_mismatched_shape() {
+    let mut m = grid();
+    let err = m.view_mut(0..2, 0..3).copy_from(&Matrix::new(3, 2, &[0; 6])).unwrap_err();
+    assert_eq!(err.to_string(), "cannot copy 3x2 matrix into 2x3 view: dimensions differ");
+    assert_eq!(m, grid());
+}
+
+#[test]
+#[should_panic(expected = "index (0, 2) out of bounds for 2x2 view")]
+fn mutable_view_index_out_of_bounds() {
+    let mut m = grid();
+    m.view_mut(0..2, 0..2)[(0, 2)] = 1;
+}